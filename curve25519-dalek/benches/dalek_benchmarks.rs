@@ -11,11 +11,14 @@ use curve25519_dalek::scalar::Scalar;
 
 static BATCH_SIZES: [usize; 5] = [1, 2, 4, 8, 16];
 static MULTISCALAR_SIZES: [usize; 13] = [1, 2, 4, 8, 16, 32, 64, 128, 256, 384, 512, 768, 1024];
+// Sizes either side of the point where the serial backend switches to batch-affine Pippenger
+static LARGE_MULTISCALAR_SIZES: [usize; 5] = [2048, 4096, 8192, 16384, 65536];
 
 mod edwards_benches {
     use super::*;
 
     use curve25519_dalek::edwards::EdwardsPoint;
+    use curve25519_dalek::edwards::EdwardsPointTable;
 
     fn compress<M: Measurement>(c: &mut BenchmarkGroup<M>) {
         let B = &constants::ED25519_BASEPOINT_POINT;
@@ -29,6 +32,38 @@ mod edwards_benches {
         });
     }
 
+    fn compress_batch<M: Measurement>(c: &mut BenchmarkGroup<M>) {
+        for batch_size in &BATCH_SIZES {
+            c.bench_with_input(
+                BenchmarkId::new("Batch EdwardsPoint compression", *batch_size),
+                &batch_size,
+                |b, &&size| {
+                    let mut rng = OsRng;
+                    let points: Vec<EdwardsPoint> = (0..size)
+                        .map(|_| EdwardsPoint::mul_base(&Scalar::random(&mut rng)))
+                        .collect();
+                    b.iter(|| EdwardsPoint::compress_batch(&points));
+                },
+            );
+        }
+    }
+
+    fn to_montgomery_batch<M: Measurement>(c: &mut BenchmarkGroup<M>) {
+        for batch_size in &BATCH_SIZES {
+            c.bench_with_input(
+                BenchmarkId::new("Batch EdwardsPoint to Montgomery", *batch_size),
+                &batch_size,
+                |b, &&size| {
+                    let mut rng = OsRng;
+                    let points: Vec<EdwardsPoint> = (0..size)
+                        .map(|_| EdwardsPoint::mul_base(&Scalar::random(&mut rng)))
+                        .collect();
+                    b.iter(|| EdwardsPoint::to_montgomery_batch(&points));
+                },
+            );
+        }
+    }
+
     fn consttime_fixed_base_scalar_mul<M: Measurement>(c: &mut BenchmarkGroup<M>) {
         let s = Scalar::from(897987897u64).invert();
         c.bench_function("Constant-time fixed-base scalar mul", move |b| {
@@ -44,6 +79,30 @@ mod edwards_benches {
         });
     }
 
+    fn consttime_variable_base_table_scalar_mul<M: Measurement>(c: &mut BenchmarkGroup<M>) {
+        let table = EdwardsPointTable::create(&constants::ED25519_BASEPOINT_POINT);
+        let s = Scalar::from(897987897u64).invert();
+        c.bench_function(
+            "Constant-time variable-base scalar mul with precomputed table",
+            move |b| b.iter(|| &table * &s),
+        );
+    }
+
+    fn vartime_fixed_base_scalar_mul<M: Measurement>(c: &mut BenchmarkGroup<M>) {
+        let s = Scalar::from(897987897u64).invert();
+        c.bench_function("Variable-time fixed-base scalar mul", move |b| {
+            b.iter(|| EdwardsPoint::vartime_mul_base(&s))
+        });
+    }
+
+    fn vartime_variable_base_scalar_mul<M: Measurement>(c: &mut BenchmarkGroup<M>) {
+        let B = &constants::ED25519_BASEPOINT_POINT;
+        let s = Scalar::from(897987897u64).invert();
+        c.bench_function("Variable-time variable-base scalar mul", move |b| {
+            b.iter(|| B.vartime_mul(&s))
+        });
+    }
+
     fn vartime_double_base_scalar_mul<M: Measurement>(c: &mut BenchmarkGroup<M>) {
         c.bench_function("Variable-time aA+bB, A variable, B fixed", |bench| {
             let mut rng = thread_rng();
@@ -62,8 +121,13 @@ mod edwards_benches {
 
         compress(&mut g);
         decompress(&mut g);
+        compress_batch(&mut g);
+        to_montgomery_batch(&mut g);
         consttime_fixed_base_scalar_mul(&mut g);
         consttime_variable_base_scalar_mul(&mut g);
+        consttime_variable_base_table_scalar_mul(&mut g);
+        vartime_fixed_base_scalar_mul(&mut g);
+        vartime_variable_base_scalar_mul(&mut g);
         vartime_double_base_scalar_mul(&mut g);
     }
 }
@@ -135,6 +199,26 @@ mod multiscalar_benches {
         }
     }
 
+    fn large_vartime_multiscalar_mul<M: Measurement>(c: &mut BenchmarkGroup<M>) {
+        for multiscalar_size in &LARGE_MULTISCALAR_SIZES {
+            c.bench_with_input(
+                BenchmarkId::new(
+                    "Variable-time variable-base multiscalar multiplication",
+                    *multiscalar_size,
+                ),
+                &multiscalar_size,
+                |b, &&size| {
+                    let points = construct_points(size);
+                    b.iter_batched(
+                        || construct_scalars(size),
+                        |scalars| EdwardsPoint::vartime_multiscalar_mul(&scalars, &points),
+                        BatchSize::LargeInput,
+                    );
+                },
+            );
+        }
+    }
+
     fn vartime_precomputed_pure_static<M: Measurement>(c: &mut BenchmarkGroup<M>) {
         for multiscalar_size in &MULTISCALAR_SIZES {
             c.bench_with_input(
@@ -222,6 +306,13 @@ mod multiscalar_benches {
         for frac in dynamic_fracs.iter() {
             vartime_precomputed_helper(&mut g, *frac);
         }
+        g.finish();
+
+        // Fewer samples, since each iteration takes a long time
+        let mut g = c.benchmark_group("large multiscalar benches");
+        g.sample_size(10);
+
+        large_vartime_multiscalar_mul(&mut g);
     }
 }
 
@@ -243,6 +334,22 @@ mod ristretto_benches {
         });
     }
 
+    fn compress_batch<M: Measurement>(c: &mut BenchmarkGroup<M>) {
+        for batch_size in &BATCH_SIZES {
+            c.bench_with_input(
+                BenchmarkId::new("Batch Ristretto encode", *batch_size),
+                &batch_size,
+                |b, &&size| {
+                    let mut rng = OsRng;
+                    let points: Vec<RistrettoPoint> = (0..size)
+                        .map(|_| RistrettoPoint::random(&mut rng))
+                        .collect();
+                    b.iter(|| RistrettoPoint::compress_batch(&points));
+                },
+            );
+        }
+    }
+
     fn double_and_compress_batch<M: Measurement>(c: &mut BenchmarkGroup<M>) {
         for batch_size in &BATCH_SIZES {
             c.bench_with_input(
@@ -265,6 +372,7 @@ mod ristretto_benches {
 
         compress(&mut g);
         decompress(&mut g);
+        compress_batch(&mut g);
         double_and_compress_batch(&mut g);
     }
 }
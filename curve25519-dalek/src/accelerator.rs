@@ -0,0 +1,406 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Delegation of Edwards point operations to an external accelerator.
+//!
+//! Some environments, such as zkVMs, provide precompiles or syscalls that perform Edwards point
+//! operations far more cheaply than the software field arithmetic of this crate. The
+//! [`EdwardsAccelerator`] trait describes the operations that can be delegated, and
+//! [`SoftwareAccelerator`] is the reference implementation used by default.
+//!
+//! The following operations are routed through the selected accelerator:
+//!
+//! * addition and subtraction of `EdwardsPoint`s, and point doubling;
+//! * [`CompressedEdwardsY::decompress`];
+//! * [`EdwardsPoint::mul_base`];
+//! * [`EdwardsPoint::vartime_double_scalar_mul_basepoint`], which together with `decompress`
+//!   is all that Ed25519 signature verification needs.
+//!
+//! # Selecting an accelerator
+//!
+//! To route these operations through a host-provided accelerator, the final binary crate
+//! implements [`EdwardsAccelerator`] and registers it with
+//! [`register_edwards_accelerator!`](crate::register_edwards_accelerator), and the whole
+//! program is built with
+//!
+//! ```sh
+//! RUSTFLAGS='--cfg curve25519_dalek_accelerator="extern"'
+//! ```
+//!
+//! Without that `cfg`, the registration is ignored and [`SoftwareAccelerator`] is used. With
+//! it, a program that does not register an accelerator fails to link.
+//!
+//! An accelerator only needs to provide `add`, `double`, `decompress` and `mul_base`; the
+//! remaining operations have default implementations in terms of these.
+//!
+//! ```ignore
+//! use curve25519_dalek::accelerator::{EdwardsAccelerator, SoftwareAccelerator};
+//! use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+//! use curve25519_dalek::Scalar;
+//!
+//! struct Precompile;
+//!
+//! impl EdwardsAccelerator for Precompile {
+//!     fn add(P: &EdwardsPoint, Q: &EdwardsPoint) -> EdwardsPoint {
+//!         host::ed_add(P, Q)
+//!     }
+//!
+//!     fn double(P: &EdwardsPoint) -> EdwardsPoint {
+//!         host::ed_add(P, P)
+//!     }
+//!
+//!     fn decompress(repr: &CompressedEdwardsY) -> Option<EdwardsPoint> {
+//!         host::ed_decompress(repr)
+//!     }
+//!
+//!     fn mul_base(scalar: &Scalar) -> EdwardsPoint {
+//!         SoftwareAccelerator::mul_base(scalar)
+//!     }
+//! }
+//!
+//! curve25519_dalek::register_edwards_accelerator!(Precompile);
+//! ```
+//!
+//! Implementations must return valid points, and must compute the same results as
+//! [`SoftwareAccelerator`] (up to the choice of extended coordinates).
+
+#![allow(non_snake_case)]
+
+use crate::constants;
+use crate::edwards::{CompressedEdwardsY, EdwardsPoint};
+use crate::scalar::Scalar;
+use crate::traits::Identity;
+
+/// Implementations of the Edwards point operations that can be delegated to an accelerator.
+///
+/// See the [module documentation](self) for how an accelerator is selected.
+pub trait EdwardsAccelerator {
+    /// Compute \\(P + Q\\).
+    fn add(P: &EdwardsPoint, Q: &EdwardsPoint) -> EdwardsPoint;
+
+    /// Compute \\(2P\\).
+    fn double(P: &EdwardsPoint) -> EdwardsPoint;
+
+    /// Decompress `repr`, returning `None` exactly when
+    /// [`CompressedEdwardsY::decompress`] would.
+    fn decompress(repr: &CompressedEdwardsY) -> Option<EdwardsPoint>;
+
+    /// Compute \\(sB\\), where \\(B\\) is the Ed25519 basepoint, in constant time.
+    fn mul_base(scalar: &Scalar) -> EdwardsPoint;
+
+    /// Compute \\(P - Q\\).
+    fn sub(P: &EdwardsPoint, Q: &EdwardsPoint) -> EdwardsPoint {
+        Self::add(P, &-Q)
+    }
+
+    /// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the Ed25519 basepoint.
+    ///
+    /// The default implementation computes \\(aA\\) by double-and-add with [`Self::double`]
+    /// and [`Self::add`], and \\(bB\\) with [`Self::mul_base`].
+    fn vartime_double_scalar_mul_basepoint(
+        a: &Scalar,
+        A: &EdwardsPoint,
+        b: &Scalar,
+    ) -> EdwardsPoint {
+        let mut aA = EdwardsPoint::identity();
+        let mut started = false;
+        for bit in a.bits_le().rev() {
+            if started {
+                aA = Self::double(&aA);
+            }
+            if bit {
+                aA = if started { Self::add(&aA, A) } else { *A };
+                started = true;
+            }
+        }
+        Self::add(&aA, &Self::mul_base(b))
+    }
+}
+
+/// The software implementation of [`EdwardsAccelerator`], used unless another accelerator is
+/// selected.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SoftwareAccelerator;
+
+impl EdwardsAccelerator for SoftwareAccelerator {
+    fn add(P: &EdwardsPoint, Q: &EdwardsPoint) -> EdwardsPoint {
+        (P + &Q.as_projective_niels()).as_extended()
+    }
+
+    fn double(P: &EdwardsPoint) -> EdwardsPoint {
+        P.as_projective().double().as_extended()
+    }
+
+    fn decompress(repr: &CompressedEdwardsY) -> Option<EdwardsPoint> {
+        use crate::edwards::decompress;
+
+        let (is_valid_y_coord, X, Y, Z) = decompress::step_1(repr);
+
+        if is_valid_y_coord.into() {
+            Some(decompress::step_2(repr, X, Y, Z))
+        } else {
+            None
+        }
+    }
+
+    fn mul_base(scalar: &Scalar) -> EdwardsPoint {
+        #[cfg(not(feature = "precomputed-tables"))]
+        {
+            scalar * constants::ED25519_BASEPOINT_POINT
+        }
+
+        #[cfg(feature = "precomputed-tables")]
+        {
+            scalar * constants::ED25519_BASEPOINT_TABLE
+        }
+    }
+
+    fn sub(P: &EdwardsPoint, Q: &EdwardsPoint) -> EdwardsPoint {
+        (P - &Q.as_projective_niels()).as_extended()
+    }
+
+    fn vartime_double_scalar_mul_basepoint(
+        a: &Scalar,
+        A: &EdwardsPoint,
+        b: &Scalar,
+    ) -> EdwardsPoint {
+        crate::backend::vartime_double_base_mul(a, A, b)
+    }
+}
+
+/// The accelerator registered with [`register_edwards_accelerator!`], which forwards each
+/// operation to the registered implementation.
+///
+/// [`register_edwards_accelerator!`]: crate::register_edwards_accelerator
+#[cfg(curve25519_dalek_accelerator = "extern")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ExternAccelerator;
+
+#[cfg(curve25519_dalek_accelerator = "extern")]
+extern "Rust" {
+    fn __curve25519_dalek_accelerator_add(P: &EdwardsPoint, Q: &EdwardsPoint) -> EdwardsPoint;
+    fn __curve25519_dalek_accelerator_sub(P: &EdwardsPoint, Q: &EdwardsPoint) -> EdwardsPoint;
+    fn __curve25519_dalek_accelerator_double(P: &EdwardsPoint) -> EdwardsPoint;
+    fn __curve25519_dalek_accelerator_decompress(repr: &CompressedEdwardsY)
+        -> Option<EdwardsPoint>;
+    fn __curve25519_dalek_accelerator_mul_base(scalar: &Scalar) -> EdwardsPoint;
+    fn __curve25519_dalek_accelerator_vartime_double_scalar_mul_basepoint(
+        a: &Scalar,
+        A: &EdwardsPoint,
+        b: &Scalar,
+    ) -> EdwardsPoint;
+}
+
+// SAFETY: the only definitions of these symbols are the safe functions generated by
+// `register_edwards_accelerator!`, which have exactly these signatures.
+#[cfg(curve25519_dalek_accelerator = "extern")]
+impl EdwardsAccelerator for ExternAccelerator {
+    fn add(P: &EdwardsPoint, Q: &EdwardsPoint) -> EdwardsPoint {
+        unsafe { __curve25519_dalek_accelerator_add(P, Q) }
+    }
+
+    fn double(P: &EdwardsPoint) -> EdwardsPoint {
+        unsafe { __curve25519_dalek_accelerator_double(P) }
+    }
+
+    fn decompress(repr: &CompressedEdwardsY) -> Option<EdwardsPoint> {
+        unsafe { __curve25519_dalek_accelerator_decompress(repr) }
+    }
+
+    fn mul_base(scalar: &Scalar) -> EdwardsPoint {
+        unsafe { __curve25519_dalek_accelerator_mul_base(scalar) }
+    }
+
+    fn sub(P: &EdwardsPoint, Q: &EdwardsPoint) -> EdwardsPoint {
+        unsafe { __curve25519_dalek_accelerator_sub(P, Q) }
+    }
+
+    fn vartime_double_scalar_mul_basepoint(
+        a: &Scalar,
+        A: &EdwardsPoint,
+        b: &Scalar,
+    ) -> EdwardsPoint {
+        unsafe { __curve25519_dalek_accelerator_vartime_double_scalar_mul_basepoint(a, A, b) }
+    }
+}
+
+/// The accelerator that the Edwards point operations of this crate are routed through.
+#[cfg(not(curve25519_dalek_accelerator = "extern"))]
+pub(crate) type Selected = SoftwareAccelerator;
+
+/// The accelerator that the Edwards point operations of this crate are routed through.
+#[cfg(curve25519_dalek_accelerator = "extern")]
+pub(crate) type Selected = ExternAccelerator;
+
+/// Register an implementation of [`EdwardsAccelerator`] for the whole program.
+///
+/// This must be invoked exactly once, in the final binary crate, and only takes effect when
+/// the program is built with `--cfg curve25519_dalek_accelerator="extern"`. See the
+/// [`accelerator`](crate::accelerator) module documentation.
+#[macro_export]
+macro_rules! register_edwards_accelerator {
+    ($accelerator:ty) => {
+        const _: () = {
+            use $crate::accelerator::EdwardsAccelerator;
+            use $crate::edwards::{CompressedEdwardsY, EdwardsPoint};
+            use $crate::Scalar;
+
+            #[no_mangle]
+            extern "Rust" fn __curve25519_dalek_accelerator_add(
+                P: &EdwardsPoint,
+                Q: &EdwardsPoint,
+            ) -> EdwardsPoint {
+                <$accelerator as EdwardsAccelerator>::add(P, Q)
+            }
+
+            #[no_mangle]
+            extern "Rust" fn __curve25519_dalek_accelerator_sub(
+                P: &EdwardsPoint,
+                Q: &EdwardsPoint,
+            ) -> EdwardsPoint {
+                <$accelerator as EdwardsAccelerator>::sub(P, Q)
+            }
+
+            #[no_mangle]
+            extern "Rust" fn __curve25519_dalek_accelerator_double(
+                P: &EdwardsPoint,
+            ) -> EdwardsPoint {
+                <$accelerator as EdwardsAccelerator>::double(P)
+            }
+
+            #[no_mangle]
+            extern "Rust" fn __curve25519_dalek_accelerator_decompress(
+                repr: &CompressedEdwardsY,
+            ) -> Option<EdwardsPoint> {
+                <$accelerator as EdwardsAccelerator>::decompress(repr)
+            }
+
+            #[no_mangle]
+            extern "Rust" fn __curve25519_dalek_accelerator_mul_base(
+                scalar: &Scalar,
+            ) -> EdwardsPoint {
+                <$accelerator as EdwardsAccelerator>::mul_base(scalar)
+            }
+
+            #[no_mangle]
+            extern "Rust" fn __curve25519_dalek_accelerator_vartime_double_scalar_mul_basepoint(
+                a: &Scalar,
+                A: &EdwardsPoint,
+                b: &Scalar,
+            ) -> EdwardsPoint {
+                <$accelerator as EdwardsAccelerator>::vartime_double_scalar_mul_basepoint(a, A, b)
+            }
+        };
+    };
+}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use core::cell::Cell;
+
+    use rand_core::OsRng;
+
+    std::thread_local! {
+        static ADDS: Cell<usize> = const { Cell::new(0) };
+        static DOUBLES: Cell<usize> = const { Cell::new(0) };
+        static DECOMPRESSIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn count(counter: &'static std::thread::LocalKey<Cell<usize>>) -> usize {
+        counter.with(|c| c.get())
+    }
+
+    /// A stand-in for a host accelerator, which counts the calls to its primitives and
+    /// otherwise relies on the default methods of `EdwardsAccelerator`.
+    struct MockAccelerator;
+
+    impl EdwardsAccelerator for MockAccelerator {
+        fn add(P: &EdwardsPoint, Q: &EdwardsPoint) -> EdwardsPoint {
+            ADDS.with(|c| c.set(c.get() + 1));
+            SoftwareAccelerator::add(P, Q)
+        }
+
+        fn double(P: &EdwardsPoint) -> EdwardsPoint {
+            DOUBLES.with(|c| c.set(c.get() + 1));
+            SoftwareAccelerator::double(P)
+        }
+
+        fn decompress(repr: &CompressedEdwardsY) -> Option<EdwardsPoint> {
+            DECOMPRESSIONS.with(|c| c.set(c.get() + 1));
+            SoftwareAccelerator::decompress(repr)
+        }
+
+        fn mul_base(scalar: &Scalar) -> EdwardsPoint {
+            SoftwareAccelerator::mul_base(scalar)
+        }
+    }
+
+    // With the extern accelerator selected, every test in the crate runs through the mock.
+    #[cfg(curve25519_dalek_accelerator = "extern")]
+    crate::register_edwards_accelerator!(MockAccelerator);
+
+    #[test]
+    fn software_accelerator_matches_operators() {
+        let P = EdwardsPoint::mul_base(&Scalar::random(&mut OsRng));
+        let Q = EdwardsPoint::mul_base(&Scalar::random(&mut OsRng));
+
+        assert_eq!(SoftwareAccelerator::add(&P, &Q), P + Q);
+        assert_eq!(SoftwareAccelerator::sub(&P, &Q), P - Q);
+        assert_eq!(SoftwareAccelerator::double(&P), P + P);
+        assert_eq!(SoftwareAccelerator::decompress(&P.compress()), Some(P));
+        assert_eq!(
+            SoftwareAccelerator::mul_base(&Scalar::ONE),
+            constants::ED25519_BASEPOINT_POINT
+        );
+    }
+
+    #[test]
+    fn default_methods_agree_with_software() {
+        let a = Scalar::random(&mut OsRng);
+        let b = Scalar::random(&mut OsRng);
+        let A = EdwardsPoint::mul_base(&Scalar::random(&mut OsRng)) + constants::EIGHT_TORSION[1];
+
+        let adds = count(&ADDS);
+        let doubles = count(&DOUBLES);
+        assert_eq!(
+            MockAccelerator::vartime_double_scalar_mul_basepoint(&a, &A, &b),
+            SoftwareAccelerator::vartime_double_scalar_mul_basepoint(&a, &A, &b)
+        );
+        assert!(count(&ADDS) > adds);
+        assert!(count(&DOUBLES) > doubles + 200);
+
+        assert_eq!(MockAccelerator::sub(&A, &A), EdwardsPoint::identity());
+        for a in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE].iter() {
+            assert_eq!(
+                MockAccelerator::vartime_double_scalar_mul_basepoint(a, &A, &b),
+                SoftwareAccelerator::vartime_double_scalar_mul_basepoint(a, &A, &b)
+            );
+        }
+
+        let decompressions = count(&DECOMPRESSIONS);
+        assert_eq!(MockAccelerator::decompress(&A.compress()), Some(A));
+        assert_eq!(count(&DECOMPRESSIONS), decompressions + 1);
+    }
+
+    #[test]
+    #[cfg(curve25519_dalek_accelerator = "extern")]
+    fn operations_are_routed_through_registered_accelerator() {
+        let P = constants::ED25519_BASEPOINT_POINT;
+
+        let adds = count(&ADDS);
+        let _ = P + P;
+        assert_eq!(count(&ADDS), adds + 1);
+
+        let decompressions = count(&DECOMPRESSIONS);
+        let _ = P.compress().decompress();
+        assert_eq!(count(&DECOMPRESSIONS), decompressions + 1);
+    }
+}
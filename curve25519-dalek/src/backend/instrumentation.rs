@@ -0,0 +1,203 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Operation counters for profiling, enabled by the `instrumentation` feature.
+//!
+//! The serial backend counts field multiplications, squarings and inversions, additions and
+//! doublings of curve points, and multiplications of unpacked scalars. The counters are global,
+//! and are shared between threads.
+//!
+//! Operations performed by the vector backend are not counted, so when profiling code that
+//! uses scalar multiplication, consider building with
+//! `--cfg curve25519_dalek_backend="serial"`.
+//!
+//! When the feature is disabled, this module does not exist and no counting code is
+//! compiled.
+//!
+//! # Example
+//!
+//! ```
+//! use curve25519_dalek::constants::ED25519_BASEPOINT_POINT as B;
+//! use curve25519_dalek::instrumentation;
+//!
+//! let before = instrumentation::snapshot();
+//! let _ = (B + B).compress();
+//! let cost = instrumentation::snapshot() - before;
+//!
+//! // Other threads may be running operations too, so these are lower bounds.
+//! assert!(cost.point_add >= 1);
+//! assert!(cost.field_invert >= 1);
+//! ```
+
+use core::ops::Sub;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// A global operation counter.
+pub(crate) struct Counter(AtomicUsize);
+
+impl Counter {
+    const fn new() -> Counter {
+        Counter(AtomicUsize::new(0))
+    }
+
+    /// Record `n` operations.
+    #[inline(always)]
+    pub(crate) fn add(&self, n: usize) {
+        #[cfg(target_has_atomic = "ptr")]
+        self.0.fetch_add(n, Ordering::Relaxed);
+
+        // Targets without atomic read-modify-write instructions are typically single-threaded
+        #[cfg(not(target_has_atomic = "ptr"))]
+        self.0.store(
+            self.0.load(Ordering::Relaxed).wrapping_add(n),
+            Ordering::Relaxed,
+        );
+    }
+
+    fn get(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn reset(&self) {
+        self.0.store(0, Ordering::Relaxed);
+    }
+}
+
+pub(crate) static FIELD_MUL: Counter = Counter::new();
+pub(crate) static FIELD_SQUARE: Counter = Counter::new();
+pub(crate) static FIELD_INVERT: Counter = Counter::new();
+pub(crate) static POINT_ADD: Counter = Counter::new();
+pub(crate) static POINT_DOUBLE: Counter = Counter::new();
+pub(crate) static SCALAR_MUL: Counter = Counter::new();
+
+/// The number of operations counted, as returned by [`snapshot`].
+///
+/// Subtracting two snapshots gives the number of operations between them.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct OperationCounts {
+    /// Field multiplications.
+    pub field_mul: usize,
+    /// Field squarings, including those computing `2*x^2`.
+    pub field_square: usize,
+    /// Field inversions. Each inversion also counts the squarings and multiplications it
+    /// performs.
+    pub field_invert: usize,
+    /// Point additions and subtractions in the serial curve models.
+    pub point_add: usize,
+    /// Point doublings in the serial curve models.
+    pub point_double: usize,
+    /// Multiplications and squarings of unpacked scalars, in either the usual or the
+    /// Montgomery domain.
+    pub scalar_mul: usize,
+}
+
+impl Sub for OperationCounts {
+    type Output = OperationCounts;
+
+    fn sub(self, earlier: OperationCounts) -> OperationCounts {
+        OperationCounts {
+            field_mul: self.field_mul.wrapping_sub(earlier.field_mul),
+            field_square: self.field_square.wrapping_sub(earlier.field_square),
+            field_invert: self.field_invert.wrapping_sub(earlier.field_invert),
+            point_add: self.point_add.wrapping_sub(earlier.point_add),
+            point_double: self.point_double.wrapping_sub(earlier.point_double),
+            scalar_mul: self.scalar_mul.wrapping_sub(earlier.scalar_mul),
+        }
+    }
+}
+
+/// Read the current operation counts.
+pub fn snapshot() -> OperationCounts {
+    OperationCounts {
+        field_mul: FIELD_MUL.get(),
+        field_square: FIELD_SQUARE.get(),
+        field_invert: FIELD_INVERT.get(),
+        point_add: POINT_ADD.get(),
+        point_double: POINT_DOUBLE.get(),
+        scalar_mul: SCALAR_MUL.get(),
+    }
+}
+
+/// Reset all operation counts to zero.
+pub fn reset() {
+    FIELD_MUL.reset();
+    FIELD_SQUARE.reset();
+    FIELD_INVERT.reset();
+    POINT_ADD.reset();
+    POINT_DOUBLE.reset();
+    SCALAR_MUL.reset();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::constants;
+    use crate::field::FieldElement;
+    use crate::scalar::Scalar;
+
+    // The counters are shared with tests running on other threads, so these tests only check
+    // lower bounds.
+
+    #[test]
+    fn field_operations_are_counted() {
+        let x = FieldElement::from_bytes(&[7u8; 32]);
+
+        let before = snapshot();
+        let y = &x * &x;
+        let z = y.pow2k(5);
+        let cost = snapshot() - before;
+        assert!(cost.field_mul >= 1);
+        assert!(cost.field_square >= 5);
+
+        let before = snapshot();
+        let _ = z.invert();
+        let cost = snapshot() - before;
+        // An inversion is 254 squarings and 11 multiplications
+        assert!(cost.field_invert >= 1);
+        assert!(cost.field_square >= 254);
+        assert!(cost.field_mul >= 11);
+    }
+
+    #[test]
+    fn point_and_scalar_operations_are_counted() {
+        let basepoint = constants::ED25519_BASEPOINT_POINT;
+
+        let before = snapshot();
+        let _ = basepoint + basepoint;
+        let _ = basepoint - basepoint;
+        let _ = basepoint.double();
+        let cost = snapshot() - before;
+        assert!(cost.point_add >= 2);
+        assert!(cost.point_double >= 1);
+
+        let a = Scalar::from(3u64);
+        let before = snapshot();
+        let _ = a * a;
+        let cost = snapshot() - before;
+        assert!(cost.scalar_mul >= 1);
+    }
+
+    #[test]
+    fn counts_subtract() {
+        let earlier = OperationCounts {
+            field_mul: 1,
+            ..OperationCounts::default()
+        };
+        let later = OperationCounts {
+            field_mul: 3,
+            point_add: 2,
+            ..OperationCounts::default()
+        };
+        assert_eq!(
+            later - earlier,
+            OperationCounts {
+                field_mul: 2,
+                point_add: 2,
+                ..OperationCounts::default()
+            }
+        );
+    }
+}
@@ -37,8 +37,22 @@
 use crate::EdwardsPoint;
 use crate::Scalar;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::backend::serial::curve_models::AffineNielsPoint;
+#[cfg(feature = "alloc")]
+use crate::window::NafLookupTable8;
+
+use crate::backend::serial::curve_models::ProjectiveNielsPoint;
+use crate::window::LookupTable;
+
 pub mod serial;
 
+#[cfg(feature = "instrumentation")]
+pub mod instrumentation;
+
 #[cfg(curve25519_dalek_backend = "simd")]
 pub mod vector;
 
@@ -74,6 +88,12 @@ fn get_selected_backend() -> BackendKind {
     BackendKind::Serial
 }
 
+/// The number of terms above which the serial backend sorts the points into Pippenger buckets
+/// with batched affine additions.  The vector backends always use their own Pippenger
+/// implementation, since their point additions are already cheaper than batched affine ones.
+#[cfg(feature = "alloc")]
+pub(crate) const BATCH_AFFINE_PIPPENGER_THRESHOLD: usize = 8192;
+
 #[allow(missing_docs)]
 #[cfg(feature = "alloc")]
 pub fn pippenger_optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<EdwardsPoint>
@@ -91,11 +111,60 @@ where
         #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
         BackendKind::Avx512 =>
             vector::scalar_mul::pippenger::spec_avx512ifma_avx512vl::Pippenger::optional_multiscalar_mul::<I, J>(scalars, points),
-        BackendKind::Serial =>
-            serial::scalar_mul::pippenger::Pippenger::optional_multiscalar_mul::<I, J>(scalars, points),
+        BackendKind::Serial => {
+            let scalars = scalars.into_iter();
+            if scalars.size_hint().0 >= BATCH_AFFINE_PIPPENGER_THRESHOLD {
+                serial::scalar_mul::batch_affine_pippenger::BatchAffinePippenger::optional_multiscalar_mul(scalars, points)
+            } else {
+                serial::scalar_mul::pippenger::Pippenger::optional_multiscalar_mul(scalars, points)
+            }
+        }
     }
 }
 
+/// The smallest number of terms given to each thread by
+/// [`parallel_pippenger_optional_multiscalar_mul`].  Pippenger's algorithm pays a fixed cost
+/// per window to sum its buckets, so smaller chunks spend too much time on that relative to
+/// the additions of the points themselves.
+#[cfg(feature = "rayon")]
+pub(crate) const PARALLEL_PIPPENGER_MIN_CHUNK: usize = 2048;
+
+/// Compute a variable-time multiscalar multiplication by splitting the terms into chunks,
+/// running Pippenger's algorithm on each chunk in parallel, and summing the results.
+///
+/// This falls back to the single-threaded [`pippenger_optional_multiscalar_mul`] when there
+/// are too few terms to give each thread at least [`PARALLEL_PIPPENGER_MIN_CHUNK`] of them.
+#[cfg(feature = "rayon")]
+pub fn parallel_pippenger_optional_multiscalar_mul<I, J>(
+    scalars: I,
+    points: J,
+) -> Option<EdwardsPoint>
+where
+    I: IntoIterator,
+    I::Item: core::borrow::Borrow<Scalar>,
+    J: IntoIterator<Item = Option<EdwardsPoint>>,
+{
+    use core::borrow::Borrow;
+    use rayon::prelude::*;
+
+    let scalars: Vec<Scalar> = scalars.into_iter().map(|s| *s.borrow()).collect();
+    let points: Vec<EdwardsPoint> = points.into_iter().collect::<Option<_>>()?;
+
+    let threads = rayon::current_num_threads();
+    let chunk_size = ((scalars.len() + threads - 1) / threads).max(PARALLEL_PIPPENGER_MIN_CHUNK);
+    if chunk_size >= scalars.len() {
+        return pippenger_optional_multiscalar_mul(scalars, points.into_iter().map(Some));
+    }
+
+    scalars
+        .par_chunks(chunk_size)
+        .zip(points.par_chunks(chunk_size))
+        .map(|(scalars, points)| {
+            pippenger_optional_multiscalar_mul(scalars, points.iter().copied().map(Some))
+        })
+        .sum::<Option<EdwardsPoint>>()
+}
+
 #[cfg(feature = "alloc")]
 pub(crate) enum VartimePrecomputedStraus {
     #[cfg(curve25519_dalek_backend = "simd")]
@@ -128,6 +197,31 @@ impl VartimePrecomputedStraus {
         }
     }
 
+    /// Construct the precomputation from tables of odd multiples of each static point.
+    pub(crate) fn from_affine_tables(tables: Vec<NafLookupTable8<AffineNielsPoint>>) -> Self {
+        match get_selected_backend() {
+            #[cfg(curve25519_dalek_backend = "simd")]
+            BackendKind::Avx2 =>
+                VartimePrecomputedStraus::Avx2(vector::scalar_mul::precomputed_straus::spec_avx2::VartimePrecomputedStraus::from_affine_tables(tables)),
+            #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+            BackendKind::Avx512 =>
+                VartimePrecomputedStraus::Avx512ifma(vector::scalar_mul::precomputed_straus::spec_avx512ifma_avx512vl::VartimePrecomputedStraus::from_affine_tables(tables)),
+            BackendKind::Serial =>
+                VartimePrecomputedStraus::Scalar(serial::scalar_mul::precomputed_straus::VartimePrecomputedStraus::from_affine_tables(tables))
+        }
+    }
+
+    /// Get the tables of odd multiples of each static point, in affine coordinates.
+    pub(crate) fn to_affine_tables(&self) -> Vec<NafLookupTable8<AffineNielsPoint>> {
+        match self {
+            #[cfg(curve25519_dalek_backend = "simd")]
+            VartimePrecomputedStraus::Avx2(inner) => inner.to_affine_tables(),
+            #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+            VartimePrecomputedStraus::Avx512ifma(inner) => inner.to_affine_tables(),
+            VartimePrecomputedStraus::Scalar(inner) => inner.to_affine_tables(),
+        }
+    }
+
     pub fn optional_mixed_multiscalar_mul<I, J, K>(
         &self,
         static_scalars: I,
@@ -236,6 +330,86 @@ pub fn variable_base_mul(point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint
     }
 }
 
+/// A table of the multiples \\([P, 2P, \ldots, 8P]\\) of a point, in the representation used by
+/// the backend that was selected when it was created.
+#[derive(Clone, Debug)]
+pub(crate) enum VariableBaseTable {
+    #[cfg(curve25519_dalek_backend = "simd")]
+    Avx2(LookupTable<vector::avx2::CachedPoint>),
+    #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+    Avx512ifma(LookupTable<vector::ifma::CachedPoint>),
+    Scalar(LookupTable<ProjectiveNielsPoint>),
+}
+
+impl VariableBaseTable {
+    pub fn create(point: &EdwardsPoint) -> Self {
+        match get_selected_backend() {
+            #[cfg(curve25519_dalek_backend = "simd")]
+            BackendKind::Avx2 => VariableBaseTable::Avx2(
+                vector::scalar_mul::variable_base::spec_avx2::create_table(point),
+            ),
+            #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+            BackendKind::Avx512 => VariableBaseTable::Avx512ifma(
+                vector::scalar_mul::variable_base::spec_avx512ifma_avx512vl::create_table(point),
+            ),
+            BackendKind::Serial => VariableBaseTable::Scalar(LookupTable::from(point)),
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for VariableBaseTable {
+    fn zeroize(&mut self) {
+        match self {
+            #[cfg(curve25519_dalek_backend = "simd")]
+            VariableBaseTable::Avx2(table) => table.0.iter_mut().for_each(|point| point.zeroize()),
+            #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+            VariableBaseTable::Avx512ifma(table) => {
+                table.0.iter_mut().for_each(|point| point.zeroize())
+            }
+            VariableBaseTable::Scalar(table) => table.zeroize(),
+        }
+    }
+}
+
+/// Perform constant-time scalar multiplication of the point whose multiples are stored in
+/// `table`.
+pub(crate) fn variable_base_mul_with_table(
+    table: &VariableBaseTable,
+    scalar: &Scalar,
+) -> EdwardsPoint {
+    match table {
+        #[cfg(curve25519_dalek_backend = "simd")]
+        VariableBaseTable::Avx2(table) => {
+            vector::scalar_mul::variable_base::spec_avx2::mul_with_table(table, scalar)
+        }
+        #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+        VariableBaseTable::Avx512ifma(table) => {
+            vector::scalar_mul::variable_base::spec_avx512ifma_avx512vl::mul_with_table(
+                table, scalar,
+            )
+        }
+        VariableBaseTable::Scalar(table) => {
+            serial::scalar_mul::variable_base::mul_with_table(table, scalar)
+        }
+    }
+}
+
+/// Perform variable-time, variable-base scalar multiplication.
+pub fn vartime_variable_base_mul(point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint {
+    match get_selected_backend() {
+        #[cfg(curve25519_dalek_backend = "simd")]
+        BackendKind::Avx2 => {
+            vector::scalar_mul::vartime_variable_base::spec_avx2::mul(point, scalar)
+        }
+        #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+        BackendKind::Avx512 => {
+            vector::scalar_mul::vartime_variable_base::spec_avx512ifma_avx512vl::mul(point, scalar)
+        }
+        BackendKind::Serial => serial::scalar_mul::vartime_variable_base::mul(point, scalar),
+    }
+}
+
 /// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the Ed25519 basepoint.
 #[allow(non_snake_case)]
 pub fn vartime_double_base_mul(a: &Scalar, A: &EdwardsPoint, b: &Scalar) -> EdwardsPoint {
@@ -121,6 +121,7 @@ impl<'b> MulAssign<&'b FieldElement2625> for FieldElement2625 {
 impl<'a, 'b> Mul<&'b FieldElement2625> for &'a FieldElement2625 {
     type Output = FieldElement2625;
     fn mul(self, rhs: &'b FieldElement2625) -> FieldElement2625 {
+        count_op!(FIELD_MUL);
         let mut self_loose = fiat_25519_loose_field_element([0; 10]);
         fiat_25519_relax(&mut self_loose, &self.0);
         let mut rhs_loose = fiat_25519_loose_field_element([0; 10]);
@@ -249,6 +250,7 @@ impl FieldElement2625 {
 
     /// Compute `self^2`.
     pub fn square(&self) -> FieldElement2625 {
+        count_op!(FIELD_SQUARE);
         let mut self_loose = fiat_25519_loose_field_element([0; 10]);
         fiat_25519_relax(&mut self_loose, &self.0);
         let mut output = FieldElement2625::ZERO;
@@ -258,6 +260,7 @@ impl FieldElement2625 {
 
     /// Compute `2*self^2`.
     pub fn square2(&self) -> FieldElement2625 {
+        count_op!(FIELD_SQUARE);
         let mut self_loose = fiat_25519_loose_field_element([0; 10]);
         fiat_25519_relax(&mut self_loose, &self.0);
         let mut square = fiat_25519_tight_field_element([0; 10]);
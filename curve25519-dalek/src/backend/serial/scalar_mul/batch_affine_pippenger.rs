@@ -0,0 +1,512 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Implementation of Pippenger's algorithm with batch-affine bucket accumulation.
+
+#![allow(non_snake_case)]
+
+use alloc::vec::Vec;
+
+use core::borrow::Borrow;
+
+use crate::constants::{MINUS_ONE, MONTGOMERY_A, SQRT_MINUS_APLUS2};
+use crate::edwards::EdwardsPoint;
+use crate::field::FieldElement;
+use crate::scalar::Scalar;
+use crate::traits::{Identity, VartimeMultiscalarMul};
+
+/// Implements a version of Pippenger's algorithm in which the points are sorted into buckets
+/// using affine additions, with one inversion shared by every addition in a round.
+///
+/// The algorithm is the same as the one described in the `pippenger` module, except for the
+/// way each bucket is summed.  Instead of adding the points into the bucket one at a time, the
+/// points in every bucket are added pairwise, in rounds:
+///
+/// 1. For each bucket, pair up its points and compute the denominator of the slope of the
+///    line through each pair.
+/// 2. Invert all of the denominators from all of the buckets at once with Montgomery's trick,
+///    as in `FieldElement::batch_invert`, which costs one inversion plus three multiplications
+///    per denominator.
+/// 3. Finish each addition, halving the number of points in each bucket.
+/// 4. Repeat until each bucket holds at most one point.
+///
+/// Affine addition on edwards25519 needs two divisions, so the additions are instead done on
+/// the birationally-equivalent Montgomery curve \\(v\^2 = u\^3 + Au\^2 + u\\), where adding
+/// two points costs 2M + 1S and one shared inversion, compared to the 8M of adding a point in
+/// projective Niels coordinates.  The points are mapped to the Montgomery curve with one batch
+/// inversion at the start, and the buckets are mapped back to extended coordinates, without
+/// any inversions, to sum them as in the `pippenger` module.
+///
+/// Since bucket additions are cheap, this uses a larger window than the `pippenger` module,
+/// which reduces the number of windows.  This only pays off for large inputs, where the cost of
+/// the bucket additions dominates: it overtakes the serial `pippenger` module at around 8192
+/// points, but not the vectorized one, whose additions are cheaper still.
+pub struct BatchAffinePippenger;
+
+impl VartimeMultiscalarMul for BatchAffinePippenger {
+    type Point = EdwardsPoint;
+
+    fn optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        let mut scalars = scalars.into_iter();
+        let size = scalars.by_ref().size_hint().0;
+
+        // Digit width in bits.  The cost of summing the buckets in each window doubles with
+        // each extra bit, so the window only grows once there are enough points to make up
+        // for it.
+        let w = if size < 1 << 14 {
+            10
+        } else if size < 1 << 16 {
+            11
+        } else if size < 1 << 18 {
+            12
+        } else {
+            13
+        };
+
+        let digits_count = (256 + w - 1) / w;
+        let buckets_count = 1 << (w - 1); // digits are signed+centered hence 2^w/2, excluding 0-th bucket
+
+        let mut digits = Vec::with_capacity(size * digits_count);
+        let mut edwards_points = Vec::with_capacity(size);
+        for (s, P) in scalars.zip(points) {
+            edwards_points.push(P?);
+            append_radix_2w(s.borrow(), w, &mut digits);
+        }
+
+        // Skip the windows above the highest nonzero digit of any scalar, so that
+        // short scalars don't pay for their bucket sums and doublings.
+        let columns_count = match digits
+            .chunks(digits_count)
+            .filter_map(|digits| digits.iter().rposition(|&d| d != 0))
+            .max()
+        {
+            Some(top) => top + 1,
+            None => return Some(EdwardsPoint::identity()),
+        };
+
+        let points = to_montgomery_affine(&edwards_points);
+
+        // Scratch space for the contents of the buckets, and the range of `contents` holding
+        // each bucket, which are reused for every window.
+        let mut contents = Vec::with_capacity(points.len());
+        let mut ranges = Vec::with_capacity(buckets_count);
+        let mut fills = Vec::with_capacity(buckets_count);
+
+        let mut columns = (0..columns_count).rev().map(|digit_index| {
+            let column = digits.iter().skip(digit_index).step_by(digits_count);
+
+            // Sort the (possibly negated) points into buckets, with a counting sort so that
+            // the points in each bucket are contiguous.
+            ranges.clear();
+            ranges.resize(buckets_count, (0, 0));
+            for (&digit, P) in column.clone().zip(points.iter()) {
+                if digit != 0 && P.is_some() {
+                    ranges[(digit.unsigned_abs() - 1) as usize].1 += 1;
+                }
+            }
+            let mut start = 0;
+            for range in ranges.iter_mut() {
+                range.0 = start;
+                start += range.1;
+            }
+
+            contents.clear();
+            contents.resize(start, MontgomeryAffine::ORDER_TWO);
+            fills.clear();
+            fills.extend(ranges.iter().map(|range| range.0));
+            for (&digit, P) in column.zip(points.iter()) {
+                if let (Some(P), false) = (P, digit == 0) {
+                    let b = (digit.unsigned_abs() - 1) as usize;
+                    contents[fills[b]] = if digit > 0 { *P } else { P.neg() };
+                    fills[b] += 1;
+                }
+            }
+
+            reduce_buckets(&mut contents, &mut ranges);
+
+            // Add the buckets applying the multiplication factor to each bucket, as in the
+            // `pippenger` module.
+            let mut buckets = ranges.iter().rev().map(|&(start, len)| match len {
+                0 => EdwardsPoint::identity(),
+                _ => contents[start].to_edwards(),
+            });
+            let mut buckets_intermediate_sum = buckets.next().expect("should have a bucket");
+            let mut buckets_sum = buckets_intermediate_sum;
+            for bucket in buckets {
+                buckets_intermediate_sum += bucket;
+                buckets_sum += buckets_intermediate_sum;
+            }
+
+            buckets_sum
+        });
+
+        // Take the high column as an initial value to avoid wasting time doubling the identity element in `fold()`.
+        let hi_column = columns.next().expect("should have more than zero digits");
+
+        Some(columns.fold(hi_column, |total, p| total.mul_by_pow_2(w as u32) + p))
+    }
+}
+
+/// A point \\((u, v)\\) on the Montgomery curve \\(v\^2 = u\^3 + Au\^2 + u\\), other than the
+/// point at infinity.
+#[derive(Copy, Clone)]
+struct MontgomeryAffine {
+    u: FieldElement,
+    v: FieldElement,
+}
+
+/// The outcome of adding two points, decided when computing the denominator of the slope.
+#[derive(Copy, Clone)]
+enum Addition {
+    /// The points are distinct, and the slope is \\((v\_2 - v\_1) / (u\_2 - u\_1)\\).
+    Chord,
+    /// The points are equal, and the slope is \\((3u\^2 + 2Au + 1) / 2v\\).
+    Tangent,
+    /// The points are negatives of each other, so the sum is the point at infinity.
+    Infinity,
+}
+
+impl MontgomeryAffine {
+    /// The point \\((0, 0)\\) of order two.
+    const ORDER_TWO: MontgomeryAffine = MontgomeryAffine {
+        u: FieldElement::ZERO,
+        v: FieldElement::ZERO,
+    };
+
+    fn neg(&self) -> MontgomeryAffine {
+        MontgomeryAffine {
+            u: self.u,
+            v: -&self.v,
+        }
+    }
+
+    /// Classify the addition of `self` and `other`, and return the denominator of its slope,
+    /// which is zero if the sum is the point at infinity.
+    fn denominator(&self, other: &MontgomeryAffine) -> (Addition, FieldElement) {
+        let du = &other.u - &self.u;
+        if !bool::from(du.is_zero()) {
+            return (Addition::Chord, du);
+        }
+
+        // Both points have the same u-coordinate, so they are equal or negatives
+        let sum_v = &self.v + &other.v;
+        if bool::from(sum_v.is_zero()) {
+            (Addition::Infinity, FieldElement::ZERO)
+        } else {
+            (Addition::Tangent, sum_v)
+        }
+    }
+
+    /// Finish the addition of `self` and `other`, given the inverse of the denominator of its
+    /// slope.
+    fn add(
+        &self,
+        other: &MontgomeryAffine,
+        addition: Addition,
+        inv: &FieldElement,
+    ) -> Option<Self> {
+        let numerator = match addition {
+            Addition::Chord => &other.v - &self.v,
+            Addition::Tangent => {
+                let uu = self.u.square();
+                &(&(&(&uu + &uu) + &uu) + &(&(&MONTGOMERY_A + &MONTGOMERY_A) * &self.u))
+                    + &FieldElement::ONE
+            }
+            Addition::Infinity => return None,
+        };
+
+        let lambda = &numerator * inv;
+        let u = &(&(&lambda.square() - &MONTGOMERY_A) - &self.u) - &other.u;
+        let v = &(&lambda * &(&self.u - &u)) - &self.v;
+        Some(MontgomeryAffine { u, v })
+    }
+
+    /// Map this point to edwards25519, without any inversions.
+    fn to_edwards(self) -> EdwardsPoint {
+        // The rational map is (x, y) = (sqrt(-486664) * u / v, (u - 1) / (u + 1)), as in
+        // `EdwardsPoint::elligator_map_to_curve`.  It is undefined when v = 0, which is the
+        // point (0, 0) of order two, which corresponds to (0, -1).  It is also undefined when
+        // u = -1, but there are no such points on the curve.
+        if bool::from(self.v.is_zero()) {
+            return EdwardsPoint {
+                X: FieldElement::ZERO,
+                Y: MINUS_ONE,
+                Z: FieldElement::ONE,
+                T: FieldElement::ZERO,
+            };
+        }
+
+        let u_plus_one = &self.u + &FieldElement::ONE;
+        let u_minus_one = &self.u - &FieldElement::ONE;
+        let c_u = &SQRT_MINUS_APLUS2 * &self.u;
+
+        EdwardsPoint {
+            X: &c_u * &u_plus_one,
+            Y: &u_minus_one * &self.v,
+            Z: &self.v * &u_plus_one,
+            T: &c_u * &u_minus_one,
+        }
+    }
+}
+
+/// Map the points to the Montgomery curve, sharing one inversion between all of them.  The
+/// identity maps to the point at infinity, which is represented by `None`.
+fn to_montgomery_affine(points: &[EdwardsPoint]) -> Vec<Option<MontgomeryAffine>> {
+    // The inverse map is
+    //
+    //     u = (Z + Y) / (Z - Y),    v = sqrt(-486664) * (Z + Y) * Z / ((Z - Y) * X),
+    //
+    // as in `EdwardsPoint::to_representative`.  Its denominator is zero for the points (0, 1)
+    // and (0, -1), which are the identity and the point (0, 0) respectively.  These are rare,
+    // so only check for them if the product of the denominators is zero.  Zeros are left
+    // unchanged by `batch_invert`, so they don't need to be filtered out.
+    let mut denominators: Vec<FieldElement> =
+        points.iter().map(|P| &(&P.Z - &P.Y) * &P.X).collect();
+    let all_nonzero = invert_nonzero(&mut denominators, &mut Vec::with_capacity(points.len()));
+    if !all_nonzero {
+        FieldElement::batch_invert(&mut denominators);
+    }
+
+    points
+        .iter()
+        .zip(denominators.iter())
+        .map(|(P, inv)| {
+            if !all_nonzero && bool::from(P.X.is_zero()) {
+                return match P.Y == P.Z {
+                    true => None,
+                    false => Some(MontgomeryAffine::ORDER_TWO),
+                };
+            }
+
+            let Z_plus_Y = &P.Z + &P.Y;
+            Some(MontgomeryAffine {
+                u: &(&Z_plus_Y * &P.X) * inv,
+                v: &(&SQRT_MINUS_APLUS2 * &(&Z_plus_Y * &P.Z)) * inv,
+            })
+        })
+        .collect()
+}
+
+/// Sum the points in each bucket, where `ranges` gives the start and length of each bucket in
+/// `contents`.  Afterwards, each bucket holds one point, or none if its sum is the point at
+/// infinity.
+fn reduce_buckets(contents: &mut [MontgomeryAffine], ranges: &mut [(usize, usize)]) {
+    let mut additions = Vec::new();
+    let mut denominators = Vec::new();
+    let mut scratch = Vec::new();
+
+    loop {
+        // Optimistically assume that every pair of points is distinct, which is almost always
+        // the case, so that the pairs don't need to be checked one by one.
+        additions.clear();
+        denominators.clear();
+        for &(start, len) in ranges.iter() {
+            for pair in contents[start..start + (len & !1)].chunks_exact(2) {
+                denominators.push(&pair[1].u - &pair[0].u);
+            }
+        }
+        if denominators.is_empty() {
+            return;
+        }
+
+        if !invert_nonzero(&mut denominators, &mut scratch) {
+            // Some pair has equal u-coordinates, so classify all of them
+            denominators.clear();
+            for &(start, len) in ranges.iter() {
+                for pair in contents[start..start + (len & !1)].chunks_exact(2) {
+                    let (addition, denominator) = pair[0].denominator(&pair[1]);
+                    additions.push(addition);
+                    denominators.push(denominator);
+                }
+            }
+            FieldElement::batch_invert(&mut denominators);
+        }
+
+        let mut inverses = denominators.iter().enumerate();
+        for (start, len) in ranges.iter_mut() {
+            let mut filled = *start;
+            for i in (*start..*start + (*len & !1)).step_by(2) {
+                let (k, inv) = inverses.next().expect("one inverse per pair");
+                let addition = additions.get(k).copied().unwrap_or(Addition::Chord);
+                if let Some(sum) = contents[i].add(&contents[i + 1], addition, inv) {
+                    contents[filled] = sum;
+                    filled += 1;
+                }
+            }
+            if *len & 1 == 1 {
+                contents[filled] = contents[*start + *len - 1];
+                filled += 1;
+            }
+            *len = filled - *start;
+        }
+    }
+}
+
+/// Replace each of `inputs` with its inverse, using `scratch` as working space, and return
+/// `true`; or if any input is zero, leave `inputs` unchanged and return `false`.
+///
+/// Unlike `FieldElement::batch_invert`, this only checks the product of the inputs for zero,
+/// rather than each input, which makes it considerably cheaper.
+fn invert_nonzero(inputs: &mut [FieldElement], scratch: &mut Vec<FieldElement>) -> bool {
+    // Montgomery's trick, as in `FieldElement::batch_invert`, but with four interleaved
+    // running products so that consecutive multiplications are independent of each other.
+    scratch.clear();
+    let mut acc = [FieldElement::ONE; 4];
+    for (i, input) in inputs.iter().enumerate() {
+        scratch.push(acc[i % 4]);
+        acc[i % 4] = &acc[i % 4] * input;
+    }
+
+    let acc01 = &acc[0] * &acc[1];
+    let acc23 = &acc[2] * &acc[3];
+    let product = &acc01 * &acc23;
+    if bool::from(product.is_zero()) {
+        return false;
+    }
+    let inv = product.invert();
+    let inv01 = &inv * &acc23;
+    let inv23 = &inv * &acc01;
+    let mut acc = [
+        &inv01 * &acc[1],
+        &inv01 * &acc[0],
+        &inv23 * &acc[3],
+        &inv23 * &acc[2],
+    ];
+
+    for (i, (input, scratch)) in inputs.iter_mut().zip(scratch.iter()).enumerate().rev() {
+        let tmp = &acc[i % 4] * input;
+        *input = &acc[i % 4] * scratch;
+        acc[i % 4] = tmp;
+    }
+    true
+}
+
+/// Append the digits of `scalar` in radix \\(2\^w\\), for \\(9 \leq w \leq 15\\), with the
+/// digits recentered into \\([-2\^w/2, 2\^w/2)\\), except for the last which may equal
+/// \\(2\^w/2\\).
+///
+/// This is the same representation as `Scalar::as_radix_2w`, which only supports
+/// \\(w \leq 8\\).  Since the scalar is less than \\(2\^{255}\\), the final carry always fits
+/// in the last digit.
+fn append_radix_2w(scalar: &Scalar, w: usize, digits: &mut Vec<i16>) {
+    debug_assert!((9..=15).contains(&w));
+
+    let mut scalar64x4 = [0u64; 4];
+    for (limb, bytes) in scalar64x4.iter_mut().zip(scalar.as_bytes().chunks_exact(8)) {
+        let mut limb_bytes = [0u8; 8];
+        limb_bytes.copy_from_slice(bytes);
+        *limb = u64::from_le_bytes(limb_bytes);
+    }
+
+    let radix: u64 = 1 << w;
+    let window_mask: u64 = radix - 1;
+    let digits_count = (256 + w - 1) / w;
+
+    let mut carry = 0u64;
+    for i in 0..digits_count {
+        let bit_offset = i * w;
+        let u64_idx = bit_offset / 64;
+        let bit_idx = bit_offset % 64;
+
+        let bit_buf: u64 = if bit_idx < 64 - w || u64_idx == 3 {
+            scalar64x4[u64_idx] >> bit_idx
+        } else {
+            (scalar64x4[u64_idx] >> bit_idx) | (scalar64x4[1 + u64_idx] << (64 - bit_idx))
+        };
+
+        let coef = carry + (bit_buf & window_mask);
+        if i == digits_count - 1 {
+            digits.push(coef as i16);
+        } else {
+            carry = (coef + (radix / 2)) >> w;
+            digits.push(((coef as i64) - (carry << w) as i64) as i16);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::backend::serial::scalar_mul::pippenger::Pippenger;
+    use crate::constants;
+
+    #[test]
+    fn radix_2w_roundtrip() {
+        let s = Scalar::from(2128506u64).invert();
+        for w in 9..=15 {
+            let mut digits = Vec::new();
+            append_radix_2w(&s, w, &mut digits);
+            assert_eq!(digits.len(), (256 + w - 1) / w);
+
+            let radix = Scalar::from(1u64 << w);
+            let recomposed = digits.iter().rev().fold(Scalar::ZERO, |acc, &d| {
+                let magnitude = Scalar::from(d.unsigned_abs() as u64);
+                acc * radix + if d < 0 { -magnitude } else { magnitude }
+            });
+            assert_eq!(recomposed, s);
+            assert!(digits.iter().all(|&d| d.unsigned_abs() <= 1 << (w - 1)));
+        }
+    }
+
+    #[test]
+    fn test_vartime_batch_affine_pippenger() {
+        // Reuse points across different tests
+        let mut n = 4096;
+        let x = Scalar::from(2128506u64).invert();
+        let y = Scalar::from(4443282u64).invert();
+        let points: Vec<_> = (0..n)
+            .map(|i| constants::ED25519_BASEPOINT_POINT * Scalar::from(1 + i as u64))
+            .collect();
+        let scalars: Vec<_> = (0..n)
+            .map(|i| x + (Scalar::from(i as u64) * y)) // fast way to make ~random but deterministic scalars
+            .collect();
+
+        while n > 0 {
+            let scalars = &scalars[0..n];
+            let points = &points[0..n];
+            let control = Pippenger::vartime_multiscalar_mul(scalars, points);
+
+            let subject = BatchAffinePippenger::vartime_multiscalar_mul(scalars, points);
+
+            assert_eq!(subject.compress(), control.compress());
+
+            n /= 4;
+        }
+    }
+
+    #[test]
+    fn batch_affine_pippenger_exceptional_points() {
+        // Repeated points, and points with their negations, land in the same bucket and
+        // exercise the doubling and point-at-infinity cases of the affine addition.  The
+        // torsion points include the identity and (0, -1), which the map to the Montgomery
+        // curve treats separately.
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let mut points = Vec::new();
+        let mut scalars = Vec::new();
+        for i in 0..64u64 {
+            let P = B * Scalar::from(i % 5);
+            points.extend_from_slice(&[P, P, -P, constants::EIGHT_TORSION[(i % 8) as usize]]);
+            scalars.extend_from_slice(&[
+                Scalar::from(i % 3 + 1),
+                Scalar::from(i % 3 + 1),
+                Scalar::from(i % 2 + 1),
+                Scalar::from(i + 1),
+            ]);
+        }
+
+        let control = Pippenger::vartime_multiscalar_mul(&scalars, &points);
+        let subject = BatchAffinePippenger::vartime_multiscalar_mul(&scalars, &points);
+        assert_eq!(subject, control);
+
+        let mut points: Vec<_> = points.into_iter().map(Some).collect();
+        points[17] = None;
+        assert!(BatchAffinePippenger::optional_multiscalar_mul(&scalars, points).is_none());
+    }
+}
@@ -23,6 +23,9 @@ pub mod variable_base;
 #[allow(missing_docs)]
 pub mod vartime_double_base;
 
+#[allow(missing_docs)]
+pub mod vartime_variable_base;
+
 #[cfg(feature = "alloc")]
 pub mod straus;
 
@@ -31,3 +34,6 @@ pub mod precomputed_straus;
 
 #[cfg(feature = "alloc")]
 pub mod pippenger;
+
+#[cfg(feature = "alloc")]
+pub mod batch_affine_pippenger;
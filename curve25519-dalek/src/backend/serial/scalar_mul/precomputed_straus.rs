@@ -30,6 +30,20 @@ pub struct VartimePrecomputedStraus {
     static_lookup_tables: Vec<NafLookupTable8<AffineNielsPoint>>,
 }
 
+impl VartimePrecomputedStraus {
+    /// Construct the precomputation from tables of odd multiples of each static point.
+    pub(crate) fn from_affine_tables(tables: Vec<NafLookupTable8<AffineNielsPoint>>) -> Self {
+        Self {
+            static_lookup_tables: tables,
+        }
+    }
+
+    /// Get the tables of odd multiples of each static point.
+    pub(crate) fn to_affine_tables(&self) -> Vec<NafLookupTable8<AffineNielsPoint>> {
+        self.static_lookup_tables.clone()
+    }
+}
+
 impl VartimePrecomputedMultiscalarMul for VartimePrecomputedStraus {
     type Point = EdwardsPoint;
 
@@ -78,11 +92,21 @@ impl VartimePrecomputedMultiscalarMul for VartimePrecomputedStraus {
         assert!(sp >= static_nafs.len());
         assert_eq!(dp, dynamic_nafs.len());
 
-        // We could save some doublings by looking for the highest
-        // nonzero NAF coefficient, but since we might have a lot of
-        // them to search, it's not clear it's worthwhile to check.
+        // Find the highest nonzero NAF coefficient of any scalar, so that
+        // short scalars don't pay for doubling the identity.  Searching from
+        // the top, this stops after a few coefficients of a full-size scalar.
+        let top = match dynamic_nafs
+            .iter()
+            .chain(static_nafs.iter())
+            .filter_map(|naf| naf.iter().rposition(|&x| x != 0))
+            .max()
+        {
+            Some(top) => top,
+            None => return Some(EdwardsPoint::identity()),
+        };
+
         let mut S = ProjectivePoint::identity();
-        for j in (0..256).rev() {
+        for j in (0..=top).rev() {
             let mut R: CompletedPoint = S.double();
 
             for i in 0..dp {
@@ -178,9 +178,20 @@ impl VartimeMultiscalarMul for Straus {
             .map(|P_opt| P_opt.map(|P| NafLookupTable5::<ProjectiveNielsPoint>::from(&P)))
             .collect::<Option<Vec<_>>>()?;
 
+        // Find the highest nonzero digit of any scalar, so that short scalars
+        // don't pay for doubling the identity.
+        let top = match nafs
+            .iter()
+            .filter_map(|naf| naf.iter().rposition(|&x| x != 0))
+            .max()
+        {
+            Some(top) => top,
+            None => return Some(EdwardsPoint::identity()),
+        };
+
         let mut r = ProjectivePoint::identity();
 
-        for i in (0..256).rev() {
+        for i in (0..=top).rev() {
             let mut t: CompletedPoint = r.double();
 
             for (naf, lookup_table) in nafs.iter().zip(lookup_tables.iter()) {
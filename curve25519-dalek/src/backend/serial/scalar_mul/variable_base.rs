@@ -7,10 +7,19 @@ use crate::traits::Identity;
 use crate::window::LookupTable;
 
 /// Perform constant-time, variable-base scalar multiplication.
-#[rustfmt::skip] // keep alignment of explanatory comments
 pub(crate) fn mul(point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint {
     // Construct a lookup table of [P,2P,3P,4P,5P,6P,7P,8P]
     let lookup_table = LookupTable::<ProjectiveNielsPoint>::from(point);
+    mul_with_table(&lookup_table, scalar)
+}
+
+/// Perform constant-time scalar multiplication of the point \\(P\\) whose multiples
+/// \\([P, 2P, \ldots, 8P]\\) are stored in `lookup_table`.
+#[rustfmt::skip] // keep alignment of explanatory comments
+pub(crate) fn mul_with_table(
+    lookup_table: &LookupTable<ProjectiveNielsPoint>,
+    scalar: &Scalar,
+) -> EdwardsPoint {
     // Setting s = scalar, compute
     //
     //    s = s_0 + s_1*16^1 + ... + s_63*16^63,
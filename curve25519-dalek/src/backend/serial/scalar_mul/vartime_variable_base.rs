@@ -0,0 +1,58 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+#![allow(non_snake_case)]
+
+use core::cmp::Ordering;
+use core::ops::{Add, Sub};
+
+use crate::backend::serial::curve_models::{CompletedPoint, ProjectiveNielsPoint, ProjectivePoint};
+use crate::edwards::EdwardsPoint;
+use crate::scalar::Scalar;
+use crate::traits::Identity;
+use crate::window::NafLookupTable5;
+
+/// Compute \\(aA\\) in variable time.
+pub fn mul(A: &EdwardsPoint, a: &Scalar) -> EdwardsPoint {
+    let a_naf = a.non_adjacent_form(5);
+    let table_A = NafLookupTable5::<ProjectiveNielsPoint>::from(A);
+
+    mul_naf(&a_naf, |x| table_A.select(x))
+}
+
+/// Compute the multiple of a point given by `naf`, where `select(x)` returns \\(xA\\) for each
+/// odd digit \\(x\\).
+fn mul_naf<T, F>(naf: &[i8; 256], select: F) -> EdwardsPoint
+where
+    F: Fn(usize) -> T,
+    for<'a, 'b> &'a EdwardsPoint:
+        Add<&'b T, Output = CompletedPoint> + Sub<&'b T, Output = CompletedPoint>,
+{
+    // Find starting index
+    let mut i = match naf.iter().rposition(|&x| x != 0) {
+        Some(i) => i,
+        None => return EdwardsPoint::identity(),
+    };
+
+    let mut r = ProjectivePoint::identity();
+    loop {
+        let mut t = r.double();
+
+        match naf[i].cmp(&0) {
+            Ordering::Greater => t = &t.as_extended() + &select(naf[i] as usize),
+            Ordering::Less => t = &t.as_extended() - &select(-naf[i] as usize),
+            Ordering::Equal => {}
+        }
+
+        r = t.as_projective();
+
+        if i == 0 {
+            break;
+        }
+        i -= 1;
+    }
+
+    r.as_extended()
+}
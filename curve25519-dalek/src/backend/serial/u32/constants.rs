@@ -84,6 +84,43 @@ pub(crate) const MONTGOMERY_A_NEG: FieldElement2625 = FieldElement2625::from_lim
     33554431,
 ]);
 
+/// `SQRT_MINUS_APLUS2` is the nonnegative square root of -(A+2) = -486664. (This is used
+/// internally within the rational map from Curve25519 to edwards25519 given in RFC 9380.)
+pub(crate) const SQRT_MINUS_APLUS2: FieldElement2625 = FieldElement2625::from_limbs([
+    54885894, 25242303, 55597453, 9067496, 51808079, 33312638, 25456129, 14121551, 54921728,
+    3972023,
+]);
+
+/// `SQRT_ID` is the nonnegative square root of i*d, where i = +sqrt(-1) and d is the
+/// Edwards curve parameter. (This is used internally within the Lizard inverse map.)
+#[cfg(feature = "lizard")]
+pub(crate) const SQRT_ID: FieldElement2625 = FieldElement2625::from_limbs([
+    39590824, 701138, 28659366, 23623507, 53932708, 32206357, 36326585, 24309414, 26167230, 1494357,
+]);
+
+/// `DP1_OVER_DM1` is (d+1)/(d-1), where d is the Edwards curve parameter. (This is used
+/// internally within the Lizard inverse map.)
+#[cfg(feature = "lizard")]
+pub(crate) const DP1_OVER_DM1: FieldElement2625 = FieldElement2625::from_limbs([
+    58833708, 32184294, 62457071, 26110240, 19032991, 27203620, 7122892, 18068959, 51019405,
+    3776288,
+]);
+
+/// `MIDOUBLE_INVSQRT_A_MINUS_D` is -2i/sqrt(a-d), where a = -1 and d are the Edwards curve
+/// parameters and i = +sqrt(-1). (This is used internally within the Lizard inverse map.)
+#[cfg(feature = "lizard")]
+pub(crate) const MIDOUBLE_INVSQRT_A_MINUS_D: FieldElement2625 = FieldElement2625::from_limbs([
+    58178520, 23970840, 26444491, 29801899, 41064376, 743696, 2900628, 27920316, 41968995, 5270573,
+]);
+
+/// `MINVSQRT_ONE_PLUS_D` is -1/sqrt(1+d), where d is the Edwards curve parameter. (This is
+/// used internally within the Lizard inverse map.)
+#[cfg(feature = "lizard")]
+pub(crate) const MINVSQRT_ONE_PLUS_D: FieldElement2625 = FieldElement2625::from_limbs([
+    38019585, 4791795, 20332186, 18653482, 46576675, 33182583, 65658549, 2817057, 12569934,
+    30919145,
+]);
+
 /// `L` is the order of base point, i.e. 2^252 +
 /// 27742317777372353535851937790883648493
 pub(crate) const L: Scalar29 = Scalar29([
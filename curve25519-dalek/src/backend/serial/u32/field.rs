@@ -126,6 +126,7 @@ impl<'a, 'b> Mul<&'b FieldElement2625> for &'a FieldElement2625 {
 
     #[rustfmt::skip] // keep alignment of z* calculations
     fn mul(self, _rhs: &'b FieldElement2625) -> FieldElement2625 {
+        count_op!(FIELD_MUL);
         /// Helper function to multiply two 32-bit integers with 64 bits
         /// of output.
         #[inline(always)]
@@ -546,6 +547,7 @@ impl FieldElement2625 {
 
     #[rustfmt::skip] // keep alignment of z* calculations
     fn square_inner(&self) -> [u64; 10] {
+        count_op!(FIELD_SQUARE);
         // Optimized version of multiplication for the case of squaring.
         // Pre- and post- conditions identical to multiplication function.
         let x = &self.0;
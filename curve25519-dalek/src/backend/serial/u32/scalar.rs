@@ -353,6 +353,7 @@ impl Scalar29 {
     /// Compute `a * b` (mod l).
     #[inline(never)]
     pub fn mul(a: &Scalar29, b: &Scalar29) -> Scalar29 {
+        count_op!(SCALAR_MUL);
         let ab = Scalar29::montgomery_reduce(&Scalar29::mul_internal(a, b));
         Scalar29::montgomery_reduce(&Scalar29::mul_internal(&ab, &constants::RR))
     }
@@ -361,6 +362,7 @@ impl Scalar29 {
     #[inline(never)]
     #[allow(dead_code)] // XXX we don't expose square() via the Scalar API
     pub fn square(&self) -> Scalar29 {
+        count_op!(SCALAR_MUL);
         let aa = Scalar29::montgomery_reduce(&Scalar29::square_internal(self));
         Scalar29::montgomery_reduce(&Scalar29::mul_internal(&aa, &constants::RR))
     }
@@ -368,12 +370,14 @@ impl Scalar29 {
     /// Compute `(a * b) / R` (mod l), where R is the Montgomery modulus 2^261
     #[inline(never)]
     pub fn montgomery_mul(a: &Scalar29, b: &Scalar29) -> Scalar29 {
+        count_op!(SCALAR_MUL);
         Scalar29::montgomery_reduce(&Scalar29::mul_internal(a, b))
     }
 
     /// Compute `(a^2) / R` (mod l) in Montgomery form, where R is the Montgomery modulus 2^261
     #[inline(never)]
     pub fn montgomery_square(&self) -> Scalar29 {
+        count_op!(SCALAR_MUL);
         Scalar29::montgomery_reduce(&Scalar29::square_internal(self))
     }
 
@@ -113,6 +113,60 @@ pub(crate) const MONTGOMERY_A_NEG: FieldElement51 = FieldElement51::from_limbs([
     2251799813685247,
 ]);
 
+/// `SQRT_MINUS_APLUS2` is the nonnegative square root of -(A+2) = -486664. (This is used
+/// internally within the rational map from Curve25519 to edwards25519 given in RFC 9380.)
+pub(crate) const SQRT_MINUS_APLUS2: FieldElement51 = FieldElement51::from_limbs([
+    1693982333959686,
+    608509411481997,
+    2235573344831311,
+    947681270984193,
+    266558006233600,
+]);
+
+/// `SQRT_ID` is the nonnegative square root of i*d, where i = +sqrt(-1) and d is the
+/// Edwards curve parameter. (This is used internally within the Lizard inverse map.)
+#[cfg(feature = "lizard")]
+pub(crate) const SQRT_ID: FieldElement51 = FieldElement51::from_limbs([
+    47052614278056,
+    1585346747125414,
+    2161332085781156,
+    1631377194372281,
+    100284626847678,
+]);
+
+/// `DP1_OVER_DM1` is (d+1)/(d-1), where d is the Edwards curve parameter. (This is used
+/// internally within the Lizard inverse map.)
+#[cfg(feature = "lizard")]
+pub(crate) const DP1_OVER_DM1: FieldElement51 = FieldElement51::from_limbs([
+    2159851467815724,
+    1752228607624431,
+    1825604053920671,
+    1212587319275468,
+    253422448836237,
+]);
+
+/// `MIDOUBLE_INVSQRT_A_MINUS_D` is -2i/sqrt(a-d), where a = -1 and d are the Edwards curve
+/// parameters and i = +sqrt(-1). (This is used internally within the Lizard inverse map.)
+#[cfg(feature = "lizard")]
+pub(crate) const MIDOUBLE_INVSQRT_A_MINUS_D: FieldElement51 = FieldElement51::from_limbs([
+    1608655899704280,
+    1999971613377227,
+    49908634785720,
+    1873700692181652,
+    353702208628067,
+]);
+
+/// `MINVSQRT_ONE_PLUS_D` is -1/sqrt(1+d), where d is the Edwards curve parameter. (This is
+/// used internally within the Lizard inverse map.)
+#[cfg(feature = "lizard")]
+pub(crate) const MINVSQRT_ONE_PLUS_D: FieldElement51 = FieldElement51::from_limbs([
+    321571956990465,
+    1251814006996634,
+    2226845496292387,
+    189049560751797,
+    2074948709371214,
+]);
+
 /// `L` is the order of base point, i.e. 2^252 + 27742317777372353535851937790883648493
 pub(crate) const L: Scalar52 = Scalar52([
     0x0002631a5cf5d3ed,
@@ -113,6 +113,7 @@ impl<'a, 'b> Mul<&'b FieldElement51> for &'a FieldElement51 {
 
     #[rustfmt::skip] // keep alignment of c* calculations
     fn mul(self, _rhs: &'b FieldElement51) -> FieldElement51 {
+        count_op!(FIELD_MUL);
         /// Helper function to multiply two 64-bit integers with 128
         /// bits of output.
         #[inline(always)]
@@ -454,6 +455,7 @@ impl FieldElement51 {
     pub fn pow2k(&self, mut k: u32) -> FieldElement51 {
 
         debug_assert!( k > 0 );
+        count_op!(FIELD_SQUARE, k);
 
         /// Multiply two 64-bit integers with 128 bits of output.
         #[inline(always)]
@@ -281,6 +281,7 @@ impl Scalar52 {
     /// Compute `a * b` (mod l)
     #[inline(never)]
     pub fn mul(a: &Scalar52, b: &Scalar52) -> Scalar52 {
+        count_op!(SCALAR_MUL);
         let ab = Scalar52::montgomery_reduce(&Scalar52::mul_internal(a, b));
         Scalar52::montgomery_reduce(&Scalar52::mul_internal(&ab, &constants::RR))
     }
@@ -289,6 +290,7 @@ impl Scalar52 {
     #[inline(never)]
     #[allow(dead_code)] // XXX we don't expose square() via the Scalar API
     pub fn square(&self) -> Scalar52 {
+        count_op!(SCALAR_MUL);
         let aa = Scalar52::montgomery_reduce(&Scalar52::square_internal(self));
         Scalar52::montgomery_reduce(&Scalar52::mul_internal(&aa, &constants::RR))
     }
@@ -296,12 +298,14 @@ impl Scalar52 {
     /// Compute `(a * b) / R` (mod l), where R is the Montgomery modulus 2^260
     #[inline(never)]
     pub fn montgomery_mul(a: &Scalar52, b: &Scalar52) -> Scalar52 {
+        count_op!(SCALAR_MUL);
         Scalar52::montgomery_reduce(&Scalar52::mul_internal(a, b))
     }
 
     /// Compute `(a^2) / R` (mod l) in Montgomery form, where R is the Montgomery modulus 2^260
     #[inline(never)]
     pub fn montgomery_square(&self) -> Scalar52 {
+        count_op!(SCALAR_MUL);
         Scalar52::montgomery_reduce(&Scalar52::square_internal(self))
     }
 
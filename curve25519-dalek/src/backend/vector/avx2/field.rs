@@ -152,6 +152,13 @@ pub enum Shuffle {
 #[derive(Clone, Copy, Debug)]
 pub struct FieldElement2625x4(pub(crate) [u32x8; 5]);
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for FieldElement2625x4 {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 use subtle::Choice;
 use subtle::ConditionallySelectable;
 
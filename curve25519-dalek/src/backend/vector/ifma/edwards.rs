@@ -18,7 +18,11 @@ use subtle::ConditionallySelectable;
 
 use curve25519_dalek_derive::unsafe_target_feature;
 
+#[cfg(feature = "alloc")]
+use crate::backend::serial::curve_models::AffineNielsPoint;
 use crate::edwards;
+#[cfg(feature = "alloc")]
+use crate::field::FieldElement;
 use crate::window::{LookupTable, NafLookupTable5};
 
 #[cfg(any(feature = "precomputed-tables", feature = "alloc"))]
@@ -33,6 +37,13 @@ pub struct ExtendedPoint(pub(super) F51x4Unreduced);
 #[derive(Copy, Clone, Debug)]
 pub struct CachedPoint(pub(super) F51x4Reduced);
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for CachedPoint {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 #[unsafe_target_feature("avx512ifma,avx512vl")]
 impl From<edwards::EdwardsPoint> for ExtendedPoint {
     fn from(P: edwards::EdwardsPoint) -> ExtendedPoint {
@@ -67,6 +78,29 @@ impl From<ExtendedPoint> for CachedPoint {
     }
 }
 
+#[cfg(feature = "alloc")]
+#[unsafe_target_feature("avx512ifma,avx512vl")]
+impl CachedPoint {
+    /// Convert this point to affine Niels coordinates, without dividing by their common
+    /// denominator.
+    ///
+    /// Returns the numerators, and the denominator, which is never zero.  Dividing each
+    /// numerator by the denominator gives the affine Niels coordinates, so that many points
+    /// can share one inversion.
+    pub(crate) fn to_affine_niels_fraction(&self) -> (AffineNielsPoint, FieldElement) {
+        // self = (121666*(Y-X), 121666*(Y+X), 2*121666*Z, -2*121665*T), and since
+        // d = -121665/121666, dividing each coordinate by Z/2 gives (y-x, y+x, 2*d*x*y).
+        let [c0, c1, c2, c3] = F51x4Unreduced::from(self.0).split();
+
+        let numerators = AffineNielsPoint {
+            y_plus_x: &c1 + &c1,
+            y_minus_x: &c0 + &c0,
+            xy2d: &c3 + &c3,
+        };
+        (numerators, c2)
+    }
+}
+
 #[unsafe_target_feature("avx512ifma,avx512vl")]
 impl Default for ExtendedPoint {
     fn default() -> ExtendedPoint {
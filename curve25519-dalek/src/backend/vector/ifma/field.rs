@@ -42,6 +42,13 @@ pub struct F51x4Unreduced(pub(crate) [u64x4; 5]);
 #[derive(Copy, Clone, Debug)]
 pub struct F51x4Reduced(pub(crate) [u64x4; 5]);
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for F51x4Reduced {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Copy, Clone)]
 pub enum Shuffle {
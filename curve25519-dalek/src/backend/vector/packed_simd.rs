@@ -77,6 +77,13 @@ macro_rules! impl_shared {
 
         impl Eq for $ty {}
 
+        #[cfg(feature = "zeroize")]
+        impl zeroize::Zeroize for $ty {
+            fn zeroize(&mut self) {
+                self.0.zeroize();
+            }
+        }
+
         #[unsafe_target_feature("avx2")]
         impl Add for $ty {
             type Output = Self;
@@ -17,6 +17,9 @@ pub mod variable_base;
 #[allow(missing_docs)]
 pub mod vartime_double_base;
 
+#[allow(missing_docs)]
+pub mod vartime_variable_base;
+
 #[allow(missing_docs)]
 #[cfg(feature = "alloc")]
 pub mod straus;
@@ -71,6 +71,17 @@ pub mod spec {
                 .map(|(s, maybe_p)| maybe_p.map(|p| (s, p)))
                 .collect::<Option<Vec<_>>>()?;
 
+            // Skip the windows above the highest nonzero digit of any scalar, so that
+            // short scalars don't pay for their bucket sums and doublings.
+            let digits_count = match scalars_points
+                .iter()
+                .filter_map(|(digits, _)| digits[..digits_count].iter().rposition(|&d| d != 0))
+                .max()
+            {
+                Some(top) => top + 1,
+                None => return Some(EdwardsPoint::identity()),
+            };
+
             // Prepare 2^w/2 buckets.
             // buckets[i] corresponds to a multiplication factor (i+1).
             let mut buckets: Vec<ExtendedPoint> = (0..buckets_count)
@@ -28,7 +28,9 @@ pub mod spec {
     #[for_target_feature("avx512ifma")]
     use crate::backend::vector::ifma::{CachedPoint, ExtendedPoint};
 
+    use crate::backend::serial::curve_models::AffineNielsPoint;
     use crate::edwards::EdwardsPoint;
+    use crate::field::FieldElement;
     use crate::scalar::Scalar;
     use crate::traits::Identity;
     use crate::traits::VartimePrecomputedMultiscalarMul;
@@ -38,6 +40,57 @@ pub mod spec {
         static_lookup_tables: Vec<NafLookupTable8<CachedPoint>>,
     }
 
+    impl VartimePrecomputedStraus {
+        /// Construct the precomputation from tables of odd multiples of each static point.
+        pub(crate) fn from_affine_tables(tables: Vec<NafLookupTable8<AffineNielsPoint>>) -> Self {
+            let convert = |P: &AffineNielsPoint| {
+                let P = (&EdwardsPoint::identity() + P).as_extended();
+                CachedPoint::from(ExtendedPoint::from(P))
+            };
+            Self {
+                static_lookup_tables: tables
+                    .iter()
+                    .map(|table| {
+                        let mut Ai = [CachedPoint::identity(); 64];
+                        for (A, P) in Ai.iter_mut().zip(table.0.iter()) {
+                            *A = convert(P);
+                        }
+                        NafLookupTable8(Ai)
+                    })
+                    .collect(),
+            }
+        }
+
+        /// Convert the tables of odd multiples of each static point to affine coordinates.
+        pub(crate) fn to_affine_tables(&self) -> Vec<NafLookupTable8<AffineNielsPoint>> {
+            let (numerators, mut denominators): (Vec<_>, Vec<_>) = self
+                .static_lookup_tables
+                .iter()
+                .flat_map(|table| table.0.iter())
+                .map(|P| P.to_affine_niels_fraction())
+                .unzip();
+
+            // Use one inversion for every entry of every table.
+            FieldElement::batch_invert(&mut denominators);
+
+            numerators
+                .chunks(64)
+                .zip(denominators.chunks(64))
+                .map(|(numerators, inverses)| {
+                    let mut Ai = [AffineNielsPoint::identity(); 64];
+                    for (A, (P, Z_inv)) in Ai.iter_mut().zip(numerators.iter().zip(inverses)) {
+                        *A = AffineNielsPoint {
+                            y_plus_x: &P.y_plus_x * Z_inv,
+                            y_minus_x: &P.y_minus_x * Z_inv,
+                            xy2d: &P.xy2d * Z_inv,
+                        };
+                    }
+                    NafLookupTable8(Ai)
+                })
+                .collect()
+        }
+    }
+
     impl VartimePrecomputedMultiscalarMul for VartimePrecomputedStraus {
         type Point = EdwardsPoint;
 
@@ -86,11 +139,21 @@ pub mod spec {
             assert!(sp >= static_nafs.len());
             assert_eq!(dp, dynamic_nafs.len());
 
-            // We could save some doublings by looking for the highest
-            // nonzero NAF coefficient, but since we might have a lot of
-            // them to search, it's not clear it's worthwhile to check.
+            // Find the highest nonzero NAF coefficient of any scalar, so that
+            // short scalars don't pay for doubling the identity.  Searching from
+            // the top, this stops after a few coefficients of a full-size scalar.
+            let top = match dynamic_nafs
+                .iter()
+                .chain(static_nafs.iter())
+                .filter_map(|naf| naf.iter().rposition(|&x| x != 0))
+                .max()
+            {
+                Some(top) => top,
+                None => return Some(EdwardsPoint::identity()),
+            };
+
             let mut R = ExtendedPoint::identity();
-            for j in (0..256).rev() {
+            for j in (0..=top).rev() {
                 R = R.double();
 
                 for i in 0..dp {
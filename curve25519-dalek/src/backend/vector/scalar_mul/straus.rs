@@ -102,9 +102,20 @@ pub mod spec {
                 .map(|P_opt| P_opt.map(|P| NafLookupTable5::<CachedPoint>::from(&P)))
                 .collect::<Option<Vec<_>>>()?;
 
+            // Find the highest nonzero digit of any scalar, so that short scalars
+            // don't pay for doubling the identity.
+            let top = match nafs
+                .iter()
+                .filter_map(|naf| naf.iter().rposition(|&x| x != 0))
+                .max()
+            {
+                Some(top) => top,
+                None => return Some(EdwardsPoint::identity()),
+            };
+
             let mut Q = ExtendedPoint::identity();
 
-            for i in (0..256).rev() {
+            for i in (0..=top).rev() {
                 Q = Q.double();
 
                 for (naf, lookup_table) in nafs.iter().zip(lookup_tables.iter()) {
@@ -19,8 +19,20 @@ pub mod spec {
 
     /// Perform constant-time, variable-base scalar multiplication.
     pub fn mul(point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint {
-        // Construct a lookup table of [P,2P,3P,4P,5P,6P,7P,8P]
-        let lookup_table = LookupTable::<CachedPoint>::from(point);
+        mul_with_table(&create_table(point), scalar)
+    }
+
+    /// Construct a lookup table of \\([P, 2P, \ldots, 8P]\\).
+    pub fn create_table(point: &EdwardsPoint) -> LookupTable<CachedPoint> {
+        LookupTable::<CachedPoint>::from(point)
+    }
+
+    /// Perform constant-time scalar multiplication of the point \\(P\\) whose multiples
+    /// \\([P, 2P, \ldots, 8P]\\) are stored in `lookup_table`.
+    pub fn mul_with_table(
+        lookup_table: &LookupTable<CachedPoint>,
+        scalar: &Scalar,
+    ) -> EdwardsPoint {
         // Setting s = scalar, compute
         //
         //    s = s_0 + s_1*16^1 + ... + s_63*16^63,
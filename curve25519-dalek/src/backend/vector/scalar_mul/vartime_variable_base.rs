@@ -0,0 +1,67 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+#![allow(non_snake_case)]
+
+#[curve25519_dalek_derive::unsafe_target_feature_specialize(
+    "avx2",
+    conditional("avx512ifma,avx512vl", nightly)
+)]
+pub mod spec {
+
+    use core::cmp::Ordering;
+
+    #[for_target_feature("avx2")]
+    use crate::backend::vector::avx2::{CachedPoint, ExtendedPoint};
+
+    #[for_target_feature("avx512ifma")]
+    use crate::backend::vector::ifma::{CachedPoint, ExtendedPoint};
+
+    use crate::edwards::EdwardsPoint;
+    use crate::scalar::Scalar;
+    use crate::traits::Identity;
+    use crate::window::NafLookupTable5;
+
+    /// Compute \\(aA\\) in variable time.
+    pub fn mul(A: &EdwardsPoint, a: &Scalar) -> EdwardsPoint {
+        let a_naf = a.non_adjacent_form(5);
+        let table_A = NafLookupTable5::<CachedPoint>::from(A);
+
+        mul_naf(&a_naf, |x| table_A.select(x))
+    }
+
+    /// Compute the multiple of a point given by `naf`, where `select(x)` returns \\(xA\\) for
+    /// each odd digit \\(x\\).
+    fn mul_naf<F: Fn(usize) -> CachedPoint>(naf: &[i8; 256], select: F) -> EdwardsPoint {
+        // Find starting index
+        let mut i = match naf.iter().rposition(|&x| x != 0) {
+            Some(i) => i,
+            None => return EdwardsPoint::identity(),
+        };
+
+        let mut Q = ExtendedPoint::identity();
+
+        loop {
+            Q = Q.double();
+
+            match naf[i].cmp(&0) {
+                Ordering::Greater => {
+                    Q = &Q + &select(naf[i] as usize);
+                }
+                Ordering::Less => {
+                    Q = &Q - &select(-naf[i] as usize);
+                }
+                Ordering::Equal => {}
+            }
+
+            if i == 0 {
+                break;
+            }
+            i -= 1;
+        }
+
+        Q.into()
+    }
+}
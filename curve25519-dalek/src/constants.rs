@@ -175,4 +175,38 @@ mod test {
         let should_be_ad_minus_one = constants::SQRT_AD_MINUS_ONE.square();
         assert_eq!(should_be_ad_minus_one, ad_minus_one);
     }
+
+    /// Test that SQRT_MINUS_APLUS2 is the nonnegative square root of -(A+2)
+    #[test]
+    fn test_sqrt_minus_aplus2() {
+        let minus_aplus2 =
+            &(&constants::MONTGOMERY_A_NEG - &FieldElement::ONE) - &FieldElement::ONE;
+        assert_eq!(constants::SQRT_MINUS_APLUS2.square(), minus_aplus2);
+        assert!(bool::from(!constants::SQRT_MINUS_APLUS2.is_negative()));
+    }
+
+    /// Test the constants used by the Lizard inverse map
+    #[test]
+    #[cfg(feature = "lizard")]
+    fn test_lizard_constants() {
+        let one = FieldElement::ONE;
+        let d = &constants::EDWARDS_D;
+
+        let (_, sqrt_id) = FieldElement::sqrt_ratio_i(&(&constants::SQRT_M1 * d), &one);
+        assert_eq!(sqrt_id, constants::SQRT_ID);
+
+        assert_eq!(&(d + &one) * &(d - &one).invert(), constants::DP1_OVER_DM1);
+
+        // -2/sqrt(a-d) happens to equal SQRT_MINUS_APLUS2, which the Lizard map uses in its place
+        let minus_double_invsqrt_a_minus_d =
+            -&(&constants::INVSQRT_A_MINUS_D + &constants::INVSQRT_A_MINUS_D);
+        assert_eq!(minus_double_invsqrt_a_minus_d, constants::SQRT_MINUS_APLUS2);
+        assert_eq!(
+            &minus_double_invsqrt_a_minus_d * &constants::SQRT_M1,
+            constants::MIDOUBLE_INVSQRT_A_MINUS_D
+        );
+
+        let (_, invsqrt_one_plus_d) = (d + &one).invsqrt();
+        assert_eq!(-&invsqrt_one_plus_d, constants::MINVSQRT_ONE_PLUS_D);
+    }
 }
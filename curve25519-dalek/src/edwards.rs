@@ -74,6 +74,11 @@
 //! see the [`curve_models` submodule][curve_models]
 //! of the internal documentation.
 //!
+//! Points can also be stored in affine coordinates as an
+//! `AffineEdwardsPoint`, which can be added to an `EdwardsPoint` more
+//! cheaply than a general point.  Use `EdwardsPoint::batch_to_affine`
+//! to convert many points with a single field inversion.
+//!
 //! ## Validity Checking
 //!
 //! There is no function for checking whether a point is valid.
@@ -93,6 +98,9 @@
 // affine and projective cakes and eat both of them too.
 #![allow(non_snake_case)]
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use core::array::TryFromSliceError;
 use core::borrow::Borrow;
 use core::fmt::Debug;
@@ -104,25 +112,27 @@ use core::ops::{Mul, MulAssign};
 use cfg_if::cfg_if;
 
 #[cfg(feature = "digest")]
-use digest::{generic_array::typenum::U64, Digest};
+use digest::{crypto_common::BlockSizeUser, generic_array::typenum::U64, Digest};
 
 #[cfg(feature = "group")]
-use {
-    group::{cofactor::CofactorGroup, prime::PrimeGroup, GroupEncoding},
-    subtle::CtOption,
-};
+use group::{cofactor::CofactorGroup, prime::PrimeGroup, GroupEncoding};
 
 #[cfg(feature = "group")]
 use rand_core::RngCore;
 
+#[cfg(any(test, feature = "rand_core"))]
+use rand_core::CryptoRngCore;
+
 use subtle::Choice;
 use subtle::ConditionallyNegatable;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
+use subtle::CtOption;
 
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
+use crate::accelerator::{self, EdwardsAccelerator};
 use crate::constants;
 
 use crate::field::FieldElement;
@@ -152,6 +162,13 @@ use crate::traits::MultiscalarMul;
 #[cfg(feature = "alloc")]
 use crate::traits::{VartimeMultiscalarMul, VartimePrecomputedMultiscalarMul};
 
+mod affine;
+pub use affine::AffineEdwardsPoint;
+#[cfg(any(feature = "alloc", feature = "precomputed-tables"))]
+mod table_encoding;
+#[cfg(any(feature = "alloc", feature = "precomputed-tables"))]
+pub use table_encoding::TableEncodingError;
+
 // ------------------------------------------------------------------------
 // Compressed points
 // ------------------------------------------------------------------------
@@ -192,21 +209,59 @@ impl CompressedEdwardsY {
     /// Returns `None` if the input is not the \\(y\\)-coordinate of a
     /// curve point.
     pub fn decompress(&self) -> Option<EdwardsPoint> {
-        let (is_valid_y_coord, X, Y, Z) = decompress::step_1(self);
+        accelerator::Selected::decompress(self)
+    }
 
-        if is_valid_y_coord.into() {
-            Some(decompress::step_2(self, X, Y, Z))
-        } else {
-            None
+    /// Attempt to decompress to an `EdwardsPoint`, using the claimed \\(x\\)-coordinate
+    /// `x_bytes` in place of the square root that [`CompressedEdwardsY::decompress`] computes.
+    ///
+    /// The hint must be the canonical encoding of the \\(x\\)-coordinate of the decompressed
+    /// point, with the sign given by `self`, and can be computed with
+    /// [`CompressedEdwardsY::decompress_hint`]. Checking it costs a few multiplications,
+    /// which is much cheaper than computing it in environments, such as zkVMs, where
+    /// exponentiation is expensive.
+    ///
+    /// Returns `None` if the input is not the \\(y\\)-coordinate of a
+    /// curve point, or if `x_bytes` is wrong. Otherwise the result is the same as returned
+    /// by `decompress`.
+    pub fn decompress_with_hint(&self, x_bytes: &[u8; 32]) -> Option<EdwardsPoint> {
+        let Y = FieldElement::from_bytes(self.as_bytes());
+        let Z = FieldElement::ONE;
+        let YY = Y.square();
+        let u = &YY - &Z; // u =  y²-1
+        let v = &(&YY * &constants::EDWARDS_D) + &Z; // v = dy²+1
+
+        // The x-coordinate must satisfy x²v = u, and have the sign from the encoding, where
+        // as in `decompress`, x = 0 is accepted with either sign bit.
+        let x = FieldElement::from_bytes(x_bytes);
+        let hint_is_canonical = x.as_bytes().ct_eq(x_bytes);
+        let mut X = x;
+        X.conditional_negate(x.is_negative());
+        let is_valid_hint = hint_is_canonical & (&X.square() * &v).ct_eq(&u);
+
+        if is_valid_hint.into() {
+            let point = decompress::step_2(self, X, Y, Z);
+            if point.X.ct_eq(&x).into() {
+                return Some(point);
+            }
         }
+        None
+    }
+
+    /// Compute natively the hint accepted by [`CompressedEdwardsY::decompress_with_hint`].
+    ///
+    /// Returns `None` if the input is not the \\(y\\)-coordinate of a
+    /// curve point.
+    pub fn decompress_hint(&self) -> Option<[u8; 32]> {
+        self.decompress().map(|P| P.X.as_bytes())
     }
 }
 
-mod decompress {
+pub(crate) mod decompress {
     use super::*;
 
     #[rustfmt::skip] // keep alignment of explanatory comments
-    pub(super) fn step_1(
+    pub(crate) fn step_1(
         repr: &CompressedEdwardsY,
     ) -> (Choice, FieldElement, FieldElement, FieldElement) {
         let Y = FieldElement::from_bytes(repr.as_bytes());
@@ -220,7 +275,7 @@ mod decompress {
     }
 
     #[rustfmt::skip]
-    pub(super) fn step_2(
+    pub(crate) fn step_2(
         repr: &CompressedEdwardsY,
         mut X: FieldElement,
         Y: FieldElement,
@@ -460,6 +515,48 @@ impl ValidityCheck for EdwardsPoint {
     }
 }
 
+/// The reason an uncompressed or raw-coordinate encoding was rejected by
+/// [`EdwardsPoint::from_affine_bytes`] or [`EdwardsPoint::from_raw_coordinates`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EdwardsPointError {
+    /// A coordinate was not the canonical encoding of a field element, i.e. it encodes an
+    /// integer \\( \geq p \\) or has the high bit set.
+    NonCanonicalCoordinate,
+    /// The projective coordinate \\(Z\\) was zero.
+    ZeroZ,
+    /// The coordinates do not satisfy the curve equation.
+    NotOnCurve,
+    /// The extended coordinates do not satisfy \\(XY = ZT\\).
+    NotOnSegreImage,
+}
+
+impl core::fmt::Display for EdwardsPointError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EdwardsPointError::NonCanonicalCoordinate => {
+                f.write_str("coordinate is not a canonical field element encoding")
+            }
+            EdwardsPointError::ZeroZ => f.write_str("projective coordinate Z is zero"),
+            EdwardsPointError::NotOnCurve => f.write_str("point is not on the curve"),
+            EdwardsPointError::NotOnSegreImage => {
+                f.write_str("extended coordinates do not satisfy X*Y = Z*T")
+            }
+        }
+    }
+}
+
+/// Decode a canonically-encoded coordinate.
+fn coordinate_from_bytes(bytes: &[u8]) -> Result<FieldElement, EdwardsPointError> {
+    let mut buf = [0u8; 32];
+    buf.copy_from_slice(bytes);
+    let fe = FieldElement::from_bytes(&buf);
+    if fe.as_bytes() == buf {
+        Ok(fe)
+    } else {
+        Err(EdwardsPointError::NonCanonicalCoordinate)
+    }
+}
+
 // ------------------------------------------------------------------------
 // Constant-time assignment
 // ------------------------------------------------------------------------
@@ -574,6 +671,201 @@ impl EdwardsPoint {
         CompressedEdwardsY(s)
     }
 
+    /// Convert a batch of points to the Montgomery model, as by
+    /// [`EdwardsPoint::to_montgomery`].
+    ///
+    /// This shares a single field inversion between all of the points, using Montgomery's
+    /// trick, so it is much faster than converting each point separately.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "rand_core", doc = "```")]
+    #[cfg_attr(not(feature = "rand_core"), doc = "```ignore")]
+    /// # use curve25519_dalek::edwards::EdwardsPoint;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// use rand_core::OsRng;
+    ///
+    /// # fn main() {
+    /// let points: Vec<EdwardsPoint> = (0..32)
+    ///     .map(|_| EdwardsPoint::mul_base(&Scalar::random(&mut OsRng)))
+    ///     .collect();
+    ///
+    /// let montgomery = EdwardsPoint::to_montgomery_batch(&points);
+    ///
+    /// for (P, P_montgomery) in points.iter().zip(montgomery.iter()) {
+    ///     assert_eq!(*P_montgomery, P.to_montgomery());
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_montgomery_batch<'a, I>(points: I) -> Vec<MontgomeryPoint>
+    where
+        I: IntoIterator<Item = &'a EdwardsPoint>,
+    {
+        // As in to_montgomery, u = (Z+Y)/(Z-Y). The identity has Z-Y = 0, which batch_invert
+        // leaves unchanged, so it is again sent to (0,0).
+        let (Us, mut Ws): (Vec<FieldElement>, Vec<FieldElement>) = points
+            .into_iter()
+            .map(|P| (&P.Z + &P.Y, &P.Z - &P.Y))
+            .unzip();
+
+        FieldElement::batch_invert(&mut Ws);
+
+        Us.iter()
+            .zip(Ws.iter())
+            .map(|(U, W_inv)| MontgomeryPoint((U * W_inv).as_bytes()))
+            .collect()
+    }
+
+    /// Compress a batch of points to `CompressedEdwardsY` format, as by
+    /// [`EdwardsPoint::compress`].
+    ///
+    /// This shares a single field inversion between all of the points, using Montgomery's
+    /// trick, so it is much faster than compressing each point separately.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "rand_core", doc = "```")]
+    #[cfg_attr(not(feature = "rand_core"), doc = "```ignore")]
+    /// # use curve25519_dalek::edwards::EdwardsPoint;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// use rand_core::OsRng;
+    ///
+    /// # fn main() {
+    /// let points: Vec<EdwardsPoint> = (0..32)
+    ///     .map(|_| EdwardsPoint::mul_base(&Scalar::random(&mut OsRng)))
+    ///     .collect();
+    ///
+    /// let compressed = EdwardsPoint::compress_batch(&points);
+    ///
+    /// for (P, P_compressed) in points.iter().zip(compressed.iter()) {
+    ///     assert_eq!(*P_compressed, P.compress());
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn compress_batch<'a, I>(points: I) -> Vec<CompressedEdwardsY>
+    where
+        I: IntoIterator<Item = &'a EdwardsPoint>,
+    {
+        let points: Vec<&EdwardsPoint> = points.into_iter().collect();
+        let mut recips: Vec<FieldElement> = points.iter().map(|P| P.Z).collect();
+
+        FieldElement::batch_invert(&mut recips);
+
+        points
+            .iter()
+            .zip(recips.iter())
+            .map(|(P, recip)| {
+                let x = &P.X * recip;
+                let y = &P.Y * recip;
+                let mut s = y.as_bytes();
+                s[31] ^= x.is_negative().unwrap_u8() << 7;
+                CompressedEdwardsY(s)
+            })
+            .collect()
+    }
+
+    /// Encode this point uncompressed, as the 64 bytes \\(x \| y\\) of its affine coordinates,
+    /// each in canonical little-endian form.
+    ///
+    /// This costs a field inversion, but decoding it with
+    /// [`EdwardsPoint::from_affine_bytes`] needs no square root, unlike
+    /// [`CompressedEdwardsY::decompress`].
+    pub fn to_affine_bytes(&self) -> [u8; 64] {
+        let affine = self.to_affine();
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&affine.x.as_bytes());
+        bytes[32..].copy_from_slice(&affine.y.as_bytes());
+        bytes
+    }
+
+    /// Decode a point from the uncompressed encoding produced by
+    /// [`EdwardsPoint::to_affine_bytes`].
+    ///
+    /// # Return
+    ///
+    /// - `Ok(P)` if `bytes` holds the canonical encodings of the coordinates of a point `P`
+    ///   on the curve;
+    /// - `Err(EdwardsPointError::NonCanonicalCoordinate)` if either coordinate is not
+    ///   canonically encoded;
+    /// - `Err(EdwardsPointError::NotOnCurve)` if \\((x, y)\\) is not on the curve.
+    ///
+    /// This function is not constant-time, and is intended for public inputs.
+    pub fn from_affine_bytes(bytes: &[u8; 64]) -> Result<EdwardsPoint, EdwardsPointError> {
+        let x = coordinate_from_bytes(&bytes[..32])?;
+        let y = coordinate_from_bytes(&bytes[32..])?;
+        let point = EdwardsPoint {
+            T: &x * &y,
+            X: x,
+            Y: y,
+            Z: FieldElement::ONE,
+        };
+
+        // With Z = 1 and T = xy, the Segre relation holds by construction.
+        if point.is_valid() {
+            Ok(point)
+        } else {
+            Err(EdwardsPointError::NotOnCurve)
+        }
+    }
+
+    /// Return the canonical encodings of the extended coordinates \\((X, Y, Z, T)\\) of this
+    /// point.
+    ///
+    /// These are not unique: every nonzero multiple of \\((X, Y, Z, T)\\) represents the same
+    /// point. Use [`EdwardsPoint::compress`] or [`EdwardsPoint::to_affine_bytes`] for a
+    /// unique encoding.
+    pub fn to_raw_coordinates(&self) -> [[u8; 32]; 4] {
+        [
+            self.X.as_bytes(),
+            self.Y.as_bytes(),
+            self.Z.as_bytes(),
+            self.T.as_bytes(),
+        ]
+    }
+
+    /// Construct a point from the canonical encodings of its extended coordinates
+    /// \\((X, Y, Z, T)\\), as produced by [`EdwardsPoint::to_raw_coordinates`].
+    ///
+    /// The coordinates are checked to represent a valid point: \\(Z\\) must be nonzero,
+    /// \\((X : Y : Z)\\) must lie on the curve, and \\(XY = ZT\\) must hold.
+    ///
+    /// # Return
+    ///
+    /// - `Ok(P)` if the coordinates represent a point `P` on the curve;
+    /// - `Err(e)` otherwise, where `e` is the first check that failed.
+    ///
+    /// This function is not constant-time, and is intended for public inputs.
+    pub fn from_raw_coordinates(
+        X: &[u8; 32],
+        Y: &[u8; 32],
+        Z: &[u8; 32],
+        T: &[u8; 32],
+    ) -> Result<EdwardsPoint, EdwardsPointError> {
+        let point = EdwardsPoint {
+            X: coordinate_from_bytes(X)?,
+            Y: coordinate_from_bytes(Y)?,
+            Z: coordinate_from_bytes(Z)?,
+            T: coordinate_from_bytes(T)?,
+        };
+
+        // The projective curve equation is satisfied by (X : Y : 0) whenever XY = 0, so Z
+        // has to be checked separately.
+        if bool::from(point.Z.is_zero()) {
+            return Err(EdwardsPointError::ZeroZ);
+        }
+        if !point.as_projective().is_valid() {
+            return Err(EdwardsPointError::NotOnCurve);
+        }
+        if &point.X * &point.Y != &point.Z * &point.T {
+            return Err(EdwardsPointError::NotOnSegreImage);
+        }
+        debug_assert!(point.is_valid());
+
+        Ok(point)
+    }
+
     #[cfg(feature = "digest")]
     /// Maps the digest of the input bytes to the curve. This is NOT a hash-to-curve function, as
     /// it produces points with a non-uniform distribution. Rather, it performs something that
@@ -605,6 +897,237 @@ impl EdwardsPoint {
             .expect("Montgomery conversion to Edwards point in Elligator failed")
             .mul_by_cofactor()
     }
+
+    /// Map a field element to a curve point, using the Elligator2 map to Curve25519 followed by
+    /// the rational map to edwards25519, as specified in
+    /// [RFC 9380, section 6.8.2](https://www.rfc-editor.org/rfc/rfc9380.html#section-6.8.2).
+    ///
+    /// The resulting point is not necessarily in the prime-order subgroup.
+    pub(crate) fn elligator_map_to_curve(r: &FieldElement) -> EdwardsPoint {
+        let (u, v) = crate::montgomery::elligator_encode_uv(r);
+
+        // The rational map is (x, y) = (sqrt(-486664) * u / v, (u - 1) / (u + 1)), which in
+        // extended coordinates is
+        //
+        //     X = sqrt(-486664) * u * (u + 1),    Y = (u - 1) * v,
+        //     Z = v * (u + 1),                    T = sqrt(-486664) * u * (u - 1).
+        //
+        // The map is undefined when v = 0 or u = -1, in which case the RFC sends the input to
+        // the identity.
+        let one = FieldElement::ONE;
+        let u_plus_one = &u + &one;
+        let u_minus_one = &u - &one;
+        let c_u = &constants::SQRT_MINUS_APLUS2 * &u;
+
+        let P = EdwardsPoint {
+            X: &c_u * &u_plus_one,
+            Y: &u_minus_one * &v,
+            Z: &v * &u_plus_one,
+            T: &c_u * &u_minus_one,
+        };
+        let is_exceptional = P.Z.is_zero();
+        EdwardsPoint::conditional_select(&P, &EdwardsPoint::identity(), is_exceptional)
+    }
+
+    /// Map an Elligator2 representative to a point on the curve.
+    ///
+    /// This applies [`MontgomeryPoint::from_representative`] followed by the rational map to
+    /// edwards25519 of
+    /// [RFC 9380, section 6.8.2](https://www.rfc-editor.org/rfc/rfc9380.html#section-6.8.2),
+    /// so that the two high bits of `representative` are ignored, and the \\(u\\)-coordinate of
+    /// the output agrees with that of the Montgomery point. This is the inverse of
+    /// [`EdwardsPoint::to_representative`].
+    ///
+    /// The output is not necessarily in the prime-order subgroup.
+    pub fn from_representative(representative: &[u8; 32]) -> EdwardsPoint {
+        let mut bytes = *representative;
+        bytes[31] &= 0x3f;
+        EdwardsPoint::elligator_map_to_curve(&FieldElement::from_bytes(&bytes))
+    }
+
+    /// Compute an Elligator2 representative of this point, that is, a 32-byte string which
+    /// [`EdwardsPoint::from_representative`] maps back to `self`.
+    ///
+    /// Only about half of all points have a representative; for the others this returns
+    /// `None`. The points \\((0, \pm 1)\\) are always reported as unrepresentable.
+    ///
+    /// Unlike [`MontgomeryPoint::to_representative`], the sign of the point determines which of
+    /// the two possible representatives is used, so only the two high bits of `tweak` are used.
+    /// They are copied into the two high bits of the representative, which are otherwise zero.
+    ///
+    /// The representative of a uniformly random point on the curve, computed with a uniformly
+    /// random `tweak`, is indistinguishable from 32 uniformly random bytes. **This is not the
+    /// case for points in the prime-order subgroup**; see
+    /// [`EdwardsPoint::mul_base_clamped_dirty`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::edwards::EdwardsPoint;
+    /// use rand_core::{OsRng, RngCore};
+    ///
+    /// # fn main() {
+    /// // Generate secret keys until the public key has a representative, which takes two
+    /// // attempts on average.
+    /// let (secret, representative) = loop {
+    ///     let mut secret = [0u8; 32];
+    ///     OsRng.fill_bytes(&mut secret);
+    ///     let tweak = OsRng.next_u32() as u8;
+    ///
+    ///     let public = EdwardsPoint::mul_base_clamped_dirty(secret);
+    ///     if let Some(representative) = public.to_representative(tweak).into() {
+    ///         break (secret, representative);
+    ///     }
+    /// };
+    ///
+    /// // The peer recovers the public key from the representative.
+    /// let public = EdwardsPoint::from_representative(&representative);
+    /// assert_eq!(public, EdwardsPoint::mul_base_clamped_dirty(secret));
+    /// # }
+    /// ```
+    pub fn to_representative(&self, tweak: u8) -> CtOption<[u8; 32]> {
+        // Invert the rational map (x, y) = (sqrt(-486664) * u / v, (u - 1) / (u + 1)):
+        //
+        //     u = (Z + Y) / (Z - Y),    v = sqrt(-486664) * (Z + Y) * Z / ((Z - Y) * X).
+        //
+        // When X = 0 or Z = Y, both u and v come out as zero, which is unrepresentable.
+        let Z_plus_Y = &self.Z + &self.Y;
+        let Z_minus_Y = &self.Z - &self.Y;
+        let inv = (&Z_minus_Y * &self.X).invert();
+        let u = &(&Z_plus_Y * &self.X) * &inv;
+        let v = &(&constants::SQRT_MINUS_APLUS2 * &(&Z_plus_Y * &self.Z)) * &inv;
+
+        let (is_representable, r) = crate::montgomery::elligator_decode(&u, v.is_negative());
+
+        let mut bytes = r.as_bytes();
+        bytes[31] |= tweak & 0xc0;
+        CtOption::new(bytes, is_representable)
+    }
+
+    #[cfg(any(test, feature = "rand_core"))]
+    /// Encode this point as 64 bytes which are indistinguishable from uniformly random bytes,
+    /// using Tibouchi's [Elligator Squared](https://eprint.iacr.org/2014/043) construction.
+    ///
+    /// Unlike [`EdwardsPoint::to_representative`], this works for every point, including those
+    /// in the prime-order subgroup, so no special key generation is needed. The encoding is
+    /// randomized, and is decoded by [`EdwardsPoint::from_uniform_bytes_sq`].
+    ///
+    /// # Implementation
+    ///
+    /// The output is a pair of representatives \\((r\_1, r\_2)\\) such that
+    /// \\(P = f(r\_1) + f(r\_2)\\), where \\(f\\) is the Elligator2 map. We pick \\(r\_1\\) at
+    /// random until \\(P - f(r\_1)\\) has a representative, which happens with probability
+    /// about \\(1/2\\) for any \\(P\\). The number of attempts therefore varies, but reveals
+    /// nothing about the point.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "rand_core", doc = "```")]
+    #[cfg_attr(not(feature = "rand_core"), doc = "```ignore")]
+    /// # use curve25519_dalek::edwards::EdwardsPoint;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// use rand_core::OsRng;
+    ///
+    /// # fn main() {
+    /// let P = EdwardsPoint::mul_base(&Scalar::from(1234u64));
+    /// let bytes = P.to_uniform_bytes(&mut OsRng);
+    ///
+    /// assert_eq!(EdwardsPoint::from_uniform_bytes_sq(&bytes), P);
+    /// # }
+    /// ```
+    pub fn to_uniform_bytes<R: CryptoRngCore + ?Sized>(&self, rng: &mut R) -> [u8; 64] {
+        let mut r_1 = [0u8; 32];
+        loop {
+            rng.fill_bytes(&mut r_1);
+            let tweak = rng.next_u32() as u8;
+
+            let Q = self - EdwardsPoint::from_representative(&r_1);
+            let r_2: Option<[u8; 32]> = Q.to_representative(tweak).into();
+            if let Some(r_2) = r_2 {
+                let mut bytes = [0u8; 64];
+                bytes[..32].copy_from_slice(&r_1);
+                bytes[32..].copy_from_slice(&r_2);
+                return bytes;
+            }
+        }
+    }
+
+    /// Decode a point from 64 bytes produced by [`EdwardsPoint::to_uniform_bytes`].
+    ///
+    /// Each half of `bytes` is mapped to the curve with
+    /// [`EdwardsPoint::from_representative`], and the results are added. Every input decodes to
+    /// a point, and uniformly random input decodes to a point which is statistically close to
+    /// uniform on the whole curve.
+    pub fn from_uniform_bytes_sq(bytes: &[u8; 64]) -> EdwardsPoint {
+        let mut r_1 = [0u8; 32];
+        let mut r_2 = [0u8; 32];
+        r_1.copy_from_slice(&bytes[..32]);
+        r_2.copy_from_slice(&bytes[32..]);
+
+        EdwardsPoint::from_representative(&r_1) + EdwardsPoint::from_representative(&r_2)
+    }
+
+    #[cfg(feature = "digest")]
+    /// Hash a message to a point in the prime-order subgroup, using the
+    /// `edwards25519_XMD:SHA-512_ELL2_RO_` suite from
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-8.5), with the hash
+    /// function `D` in place of SHA-512.
+    ///
+    /// The output is indistinguishable from a random point when `D` is SHA-512, or any other
+    /// hash with a 64-byte output that behaves like a random oracle. The domain separation tag
+    /// `dst` should be unique to the protocol and the purpose the hash is used for; see
+    /// section 3.1 of the RFC.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::edwards::EdwardsPoint;
+    /// use sha2::Sha512;
+    ///
+    /// # fn main() {
+    /// let dst = b"MyProtocol-V1-CS01-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+    /// let P = EdwardsPoint::hash_to_curve::<Sha512>(b"Hello world", dst);
+    ///
+    /// assert!(P.is_torsion_free());
+    /// # }
+    /// ```
+    pub fn hash_to_curve<D>(msg: &[u8], dst: &[u8]) -> EdwardsPoint
+    where
+        D: Digest<OutputSize = U64> + BlockSizeUser,
+    {
+        let [r_0, r_1] = FieldElement::hash_to_field::<D, 2>(msg, dst);
+        let Q_0 = EdwardsPoint::elligator_map_to_curve(&r_0);
+        let Q_1 = EdwardsPoint::elligator_map_to_curve(&r_1);
+
+        (Q_0 + Q_1).mul_by_cofactor()
+    }
+
+    #[cfg(feature = "digest")]
+    /// Encode a message to a point in the prime-order subgroup, using the
+    /// `edwards25519_XMD:SHA-512_ELL2_NU_` suite from
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-8.5), with the hash
+    /// function `D` in place of SHA-512.
+    ///
+    /// This is roughly twice as fast as [`EdwardsPoint::hash_to_curve`], but its output is
+    /// **not** uniformly distributed: only about half of the points in the prime-order subgroup
+    /// can be produced. Unless a protocol explicitly calls for a nonuniform encoding, use
+    /// [`EdwardsPoint::hash_to_curve`] instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is empty.
+    pub fn encode_to_curve<D>(msg: &[u8], dst: &[u8]) -> EdwardsPoint
+    where
+        D: Digest<OutputSize = U64> + BlockSizeUser,
+    {
+        let [r] = FieldElement::hash_to_field::<D, 1>(msg, dst);
+
+        EdwardsPoint::elligator_map_to_curve(&r).mul_by_cofactor()
+    }
 }
 
 // ------------------------------------------------------------------------
@@ -614,7 +1137,7 @@ impl EdwardsPoint {
 impl EdwardsPoint {
     /// Add this point to itself.
     pub(crate) fn double(&self) -> EdwardsPoint {
-        self.as_projective().double().as_extended()
+        accelerator::Selected::double(self)
     }
 }
 
@@ -625,7 +1148,7 @@ impl EdwardsPoint {
 impl<'a, 'b> Add<&'b EdwardsPoint> for &'a EdwardsPoint {
     type Output = EdwardsPoint;
     fn add(self, other: &'b EdwardsPoint) -> EdwardsPoint {
-        (self + &other.as_projective_niels()).as_extended()
+        accelerator::Selected::add(self, other)
     }
 }
 
@@ -646,7 +1169,7 @@ define_add_assign_variants!(LHS = EdwardsPoint, RHS = EdwardsPoint);
 impl<'a, 'b> Sub<&'b EdwardsPoint> for &'a EdwardsPoint {
     type Output = EdwardsPoint;
     fn sub(self, other: &'b EdwardsPoint) -> EdwardsPoint {
-        (self - &other.as_projective_niels()).as_extended()
+        accelerator::Selected::sub(self, other)
     }
 }
 
@@ -746,15 +1269,7 @@ impl EdwardsPoint {
     /// Uses precomputed basepoint tables when the `precomputed-tables` feature
     /// is enabled, trading off increased code size for ~4x better performance.
     pub fn mul_base(scalar: &Scalar) -> Self {
-        #[cfg(not(feature = "precomputed-tables"))]
-        {
-            scalar * constants::ED25519_BASEPOINT_POINT
-        }
-
-        #[cfg(feature = "precomputed-tables")]
-        {
-            scalar * constants::ED25519_BASEPOINT_TABLE
-        }
+        accelerator::Selected::mul_base(scalar)
     }
 
     /// Multiply this point by `clamp_integer(bytes)`. For a description of clamping, see
@@ -784,6 +1299,25 @@ impl EdwardsPoint {
         };
         Self::mul_base(&s)
     }
+
+    /// Multiply the basepoint by `clamp_integer(bytes)`, and add a point of small order chosen
+    /// by the three low bits of `bytes`.
+    ///
+    /// The public keys produced by [`Self::mul_base_clamped`] all lie in the prime-order
+    /// subgroup, so their Elligator2 representatives can be told apart from random bytes.
+    /// Adding a low-order component fixes this, and since X25519 clamps the low three bits of
+    /// the peer's scalar, it does not change the shared secret. The low-order component is the
+    /// same as the one used by obfs4, so the resulting representatives are compatible.
+    pub fn mul_base_clamped_dirty(bytes: [u8; 32]) -> Self {
+        // obfs4 adds [3k]T, where k is the low three bits and T = EIGHT_TORSION[1].
+        let index = (bytes[0] & 7).wrapping_mul(3) & 7;
+        let mut torsion = EdwardsPoint::identity();
+        for (i, T) in constants::EIGHT_TORSION.iter().enumerate() {
+            torsion.conditional_assign(T, (i as u8).ct_eq(&index));
+        }
+
+        Self::mul_base_clamped(bytes) + torsion
+    }
 }
 
 // ------------------------------------------------------------------------
@@ -855,6 +1389,13 @@ impl VartimeMultiscalarMul for EdwardsPoint {
         if size < 190 {
             crate::backend::straus_optional_multiscalar_mul(scalars, points)
         } else {
+            #[cfg(feature = "rayon")]
+            if size >= 2 * crate::backend::PARALLEL_PIPPENGER_MIN_CHUNK {
+                return crate::backend::parallel_pippenger_optional_multiscalar_mul(
+                    scalars, points,
+                );
+            }
+
             crate::backend::pippenger_optional_multiscalar_mul(scalars, points)
         }
     }
@@ -897,6 +1438,111 @@ impl VartimePrecomputedMultiscalarMul for VartimeEdwardsPrecomputation {
     }
 }
 
+/// Accumulates the terms of a variable-time multiscalar multiplication one at a time, for
+/// callers that cannot collect all of the terms before calling [`VartimeMultiscalarMul`].
+///
+/// Pushed terms are buffered until `capacity` of them have arrived, and are then multiplied
+/// with [`VartimeMultiscalarMul`] and added to a running total.  Memory use is bounded by the
+/// capacity no matter how many terms are pushed, and since the partial results are exact,
+/// [`finish`](EdwardsMultiscalarAccumulator::finish) returns the same point as a single call
+/// with every term.  Larger capacities make each flush cheaper per term.
+///
+/// Like [`VartimeMultiscalarMul`], this runs in variable time, so the scalars and points must
+/// be public.
+///
+/// # Example
+///
+/// ```
+/// use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+/// use curve25519_dalek::edwards::EdwardsMultiscalarAccumulator;
+/// use curve25519_dalek::edwards::EdwardsPoint;
+/// use curve25519_dalek::scalar::Scalar;
+/// use curve25519_dalek::traits::VartimeMultiscalarMul;
+///
+/// let scalars: Vec<_> = (1..=10u64).map(Scalar::from).collect();
+/// let points: Vec<_> = (1..=10u64).map(|i| ED25519_BASEPOINT_POINT * Scalar::from(i)).collect();
+///
+/// let mut accumulator = EdwardsMultiscalarAccumulator::with_capacity(4);
+/// for (scalar, point) in scalars.iter().zip(points.iter()) {
+///     accumulator.push(*scalar, *point);
+/// }
+///
+/// assert_eq!(
+///     accumulator.finish(),
+///     EdwardsPoint::vartime_multiscalar_mul(&scalars, &points),
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct EdwardsMultiscalarAccumulator {
+    scalars: Vec<Scalar>,
+    points: Vec<EdwardsPoint>,
+    capacity: usize,
+    total: EdwardsPoint,
+}
+
+#[cfg(feature = "alloc")]
+impl EdwardsMultiscalarAccumulator {
+    /// The number of terms buffered by [`EdwardsMultiscalarAccumulator::new`].
+    pub const DEFAULT_CAPACITY: usize = 4096;
+
+    /// Create an empty accumulator which buffers up to
+    /// [`DEFAULT_CAPACITY`](EdwardsMultiscalarAccumulator::DEFAULT_CAPACITY) terms.
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Create an empty accumulator which buffers up to `capacity` terms.
+    ///
+    /// # Panics
+    ///
+    /// If `capacity` is zero.
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be nonzero");
+
+        EdwardsMultiscalarAccumulator {
+            scalars: Vec::with_capacity(capacity),
+            points: Vec::with_capacity(capacity),
+            capacity,
+            total: EdwardsPoint::identity(),
+        }
+    }
+
+    /// Add the term `scalar * point`.
+    pub fn push(&mut self, scalar: Scalar, point: EdwardsPoint) {
+        self.scalars.push(scalar);
+        self.points.push(point);
+
+        if self.scalars.len() == self.capacity {
+            self.flush();
+        }
+    }
+
+    /// Return the sum of all of the pushed terms.
+    pub fn finish(mut self) -> EdwardsPoint {
+        self.flush();
+        self.total
+    }
+
+    /// Multiply the buffered terms, add them to the total, and clear the buffer.
+    fn flush(&mut self) {
+        if self.scalars.is_empty() {
+            return;
+        }
+
+        self.total += EdwardsPoint::vartime_multiscalar_mul(&self.scalars, &self.points);
+        self.scalars.clear();
+        self.points.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for EdwardsMultiscalarAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EdwardsPoint {
     /// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the Ed25519 basepoint.
     pub fn vartime_double_scalar_mul_basepoint(
@@ -904,10 +1550,99 @@ impl EdwardsPoint {
         A: &EdwardsPoint,
         b: &Scalar,
     ) -> EdwardsPoint {
-        crate::backend::vartime_double_base_mul(a, A, b)
+        accelerator::Selected::vartime_double_scalar_mul_basepoint(a, A, b)
+    }
+
+    /// Compute `scalar * self` in variable time.
+    ///
+    /// The running time depends on both `scalar` and `self`, so this must only be used when
+    /// both are public.
+    pub fn vartime_mul(&self, scalar: &Scalar) -> EdwardsPoint {
+        crate::backend::vartime_variable_base_mul(self, scalar)
+    }
+
+    /// Compute `scalar * B` in variable time, where `B` is the Ed25519 basepoint.
+    ///
+    /// The running time depends on `scalar`, so this must only be used when `scalar` is public.
+    /// With the `precomputed-tables` feature, this is the same as [`EdwardsPoint::mul_base`],
+    /// since the basepoint table is faster than any variable-time method.
+    pub fn vartime_mul_base(scalar: &Scalar) -> EdwardsPoint {
+        #[cfg(feature = "precomputed-tables")]
+        {
+            Self::mul_base(scalar)
+        }
+
+        #[cfg(not(feature = "precomputed-tables"))]
+        {
+            constants::ED25519_BASEPOINT_POINT.vartime_mul(scalar)
+        }
+    }
+}
+
+/// A precomputed table of the multiples \\([P, 2P, \ldots, 8P]\\) of an arbitrary point
+/// \\(P\\), for constant-time scalar multiplication by the same point many times.
+///
+/// Multiplying an `EdwardsPoint` by a `Scalar` builds this table on every call. Building it
+/// once only saves the seven additions of that step, which is small next to the cost of the
+/// multiplication itself; for a large speedup on a fixed point, use an
+/// [`EdwardsBasepointTable`] instead.
+///
+/// The table is only 8 entries long, and when the `zeroize` feature is enabled it is
+/// overwritten with zeroes when it is dropped.
+///
+/// # Example
+///
+/// ```
+/// use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+/// use curve25519_dalek::edwards::EdwardsPointTable;
+/// use curve25519_dalek::scalar::Scalar;
+///
+/// let P = ED25519_BASEPOINT_POINT * Scalar::from(17u64);
+/// let table = EdwardsPointTable::create(&P);
+///
+/// let s = Scalar::from(31u64);
+/// assert_eq!(&table * &s, P * s);
+/// ```
+#[derive(Clone, Debug)]
+pub struct EdwardsPointTable(crate::backend::VariableBaseTable);
+
+impl EdwardsPointTable {
+    /// Create a table of multiples of `point`.
+    pub fn create(point: &EdwardsPoint) -> EdwardsPointTable {
+        EdwardsPointTable(crate::backend::VariableBaseTable::create(point))
     }
 }
 
+impl Mul<&Scalar> for &EdwardsPointTable {
+    type Output = EdwardsPoint;
+
+    /// Constant-time scalar multiplication: compute `scalar * P`, where `P` is the point the
+    /// table was created from.
+    fn mul(self, scalar: &Scalar) -> EdwardsPoint {
+        crate::backend::variable_base_mul_with_table(&self.0, scalar)
+    }
+}
+
+impl Mul<&EdwardsPointTable> for &Scalar {
+    type Output = EdwardsPoint;
+
+    /// Constant-time scalar multiplication: compute `self * P`, where `P` is the point the
+    /// table was created from.
+    fn mul(self, table: &EdwardsPointTable) -> EdwardsPoint {
+        table * self
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for EdwardsPointTable {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for EdwardsPointTable {}
+
 #[cfg(feature = "precomputed-tables")]
 macro_rules! impl_basepoint_table {
     (Name = $name:ident, LookupTable = $table:ident, Point = $point:ty, Radix = $radix:expr, Additions = $adds:expr) => {
@@ -1617,6 +2352,8 @@ mod test {
     #[cfg(feature = "precomputed-tables")]
     use crate::constants::ED25519_BASEPOINT_TABLE;
 
+    use crate::field::fe_from_rfc_hex;
+
     /// X coordinate of the basepoint.
     /// = 15112221349535400772501151409588531511454012693041857206046113283949847762202
     static BASE_X_COORD_BYTES: [u8; 32] = [
@@ -1702,6 +2439,182 @@ mod test {
         assert_eq!(minus_basepoint.T, -(&constants::ED25519_BASEPOINT_POINT.T));
     }
 
+    /// Test that batch compression and conversion agree with the single-point versions,
+    /// including for the identity and other torsion points.
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn compress_and_to_montgomery_batch() {
+        let mut rng = rand::thread_rng();
+
+        let mut points: Vec<EdwardsPoint> = (0..64)
+            .map(|_| EdwardsPoint::mul_base(&Scalar::random(&mut rng)))
+            .collect();
+        points.extend_from_slice(&constants::EIGHT_TORSION);
+        points.push(points[0] + constants::EIGHT_TORSION[3]);
+
+        let compressed = EdwardsPoint::compress_batch(&points);
+        let montgomery = EdwardsPoint::to_montgomery_batch(&points);
+        assert_eq!(compressed.len(), points.len());
+        assert_eq!(montgomery.len(), points.len());
+        for ((P, P_compressed), P_montgomery) in points.iter().zip(compressed).zip(montgomery) {
+            assert_eq!(P_compressed, P.compress());
+            assert_eq!(P_montgomery, P.to_montgomery());
+        }
+
+        assert!(EdwardsPoint::compress_batch(&[]).is_empty());
+        assert!(EdwardsPoint::to_montgomery_batch(&[]).is_empty());
+    }
+
+    /// Test the uncompressed encoding against the basepoint coordinates, and that it
+    /// roundtrips for points with Z != 1.
+    #[test]
+    fn affine_bytes_roundtrip() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let B_bytes = B.to_affine_bytes();
+        assert_eq!(B_bytes[..32], BASE_X_COORD_BYTES);
+        assert_eq!(B_bytes[32..], constants::ED25519_BASEPOINT_COMPRESSED.0);
+        assert_eq!(EdwardsPoint::from_affine_bytes(&B_bytes), Ok(B));
+
+        let mut rng = rand::thread_rng();
+        let mut points = constants::EIGHT_TORSION;
+        points[0] = EdwardsPoint::mul_base(&Scalar::random(&mut rng));
+        points[1] = points[0] + constants::EIGHT_TORSION[1];
+        for P in points.iter() {
+            assert_eq!(
+                EdwardsPoint::from_affine_bytes(&P.to_affine_bytes()),
+                Ok(*P)
+            );
+        }
+    }
+
+    #[test]
+    fn affine_bytes_rejects_invalid() {
+        let B_bytes = constants::ED25519_BASEPOINT_POINT.to_affine_bytes();
+
+        // y = 1 is the identity, so x = 1 is off the curve
+        let mut bytes = [0u8; 64];
+        bytes[0] = 1;
+        bytes[32] = 1;
+        assert_eq!(
+            EdwardsPoint::from_affine_bytes(&bytes),
+            Err(EdwardsPointError::NotOnCurve)
+        );
+
+        // x + p encodes the same field element as x, but is not canonical
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&FieldElement::MINUS_ONE.as_bytes());
+        bytes[0] += 1;
+        bytes[32] = 1;
+        assert_eq!(
+            EdwardsPoint::from_affine_bytes(&bytes),
+            Err(EdwardsPointError::NonCanonicalCoordinate)
+        );
+
+        let mut bytes = B_bytes;
+        bytes[63] |= 0x80;
+        assert_eq!(
+            EdwardsPoint::from_affine_bytes(&bytes),
+            Err(EdwardsPointError::NonCanonicalCoordinate)
+        );
+    }
+
+    #[test]
+    fn raw_coordinates_roundtrip() {
+        let mut rng = rand::thread_rng();
+        let P = EdwardsPoint::mul_base(&Scalar::random(&mut rng));
+        let points = [
+            EdwardsPoint::identity(),
+            constants::ED25519_BASEPOINT_POINT,
+            P,
+            P + constants::EIGHT_TORSION[5],
+        ];
+        for P in points.iter() {
+            let [X, Y, Z, T] = P.to_raw_coordinates();
+            let Q = EdwardsPoint::from_raw_coordinates(&X, &Y, &Z, &T);
+            assert_eq!(Q, Ok(*P));
+            assert_eq!(Q.map(|Q| Q.Z), Ok(P.Z));
+        }
+    }
+
+    #[test]
+    fn raw_coordinates_rejects_invalid() {
+        let zero = [0u8; 32];
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        let [X, Y, Z, T] = constants::ED25519_BASEPOINT_POINT.to_raw_coordinates();
+
+        // (1 : 0 : 0) satisfies the homogenized curve equation, and XY = ZT = 0
+        assert_eq!(
+            EdwardsPoint::from_raw_coordinates(&one, &zero, &zero, &zero),
+            Err(EdwardsPointError::ZeroZ)
+        );
+        assert_eq!(
+            EdwardsPoint::from_raw_coordinates(&zero, &zero, &zero, &zero),
+            Err(EdwardsPointError::ZeroZ)
+        );
+        assert_eq!(
+            EdwardsPoint::from_raw_coordinates(&Y, &X, &Z, &T),
+            Err(EdwardsPointError::NotOnCurve)
+        );
+        assert_eq!(
+            EdwardsPoint::from_raw_coordinates(&zero, &one, &one, &one),
+            Err(EdwardsPointError::NotOnSegreImage)
+        );
+        assert_eq!(
+            EdwardsPoint::from_raw_coordinates(&X, &Y, &Z, &[0xff; 32]),
+            Err(EdwardsPointError::NonCanonicalCoordinate)
+        );
+    }
+
+    /// Test that hint-assisted decompression agrees with `decompress`, including for
+    /// invalid encodings and points with x = 0, and rejects wrong hints.
+    #[test]
+    fn decompress_with_hint_agrees_with_decompress() {
+        let mut rng = rand::thread_rng();
+        let mut encodings = [CompressedEdwardsY::default(); 24];
+        for (i, encoding) in encodings.iter_mut().enumerate() {
+            *encoding = match i {
+                0..=7 => EdwardsPoint::mul_base(&Scalar::random(&mut rng)).compress(),
+                8..=15 => constants::EIGHT_TORSION[i - 8].compress(),
+                _ => {
+                    // Small y, some of which are not on the curve
+                    let mut bytes = [0u8; 32];
+                    bytes[0] = i as u8;
+                    CompressedEdwardsY(bytes)
+                }
+            };
+        }
+        // The identity with the sign bit set, which `decompress` accepts
+        encodings[23].0 = [0u8; 32];
+        encodings[23].0[0] = 1;
+        encodings[23].0[31] = 0x80;
+
+        let mut num_invalid = 0;
+        for encoding in encodings.iter() {
+            let P = encoding.decompress();
+            match encoding.decompress_hint() {
+                Some(x_bytes) => {
+                    assert_eq!(encoding.decompress_with_hint(&x_bytes), P);
+
+                    let x = FieldElement::from_bytes(&x_bytes);
+                    if !bool::from(x.is_zero()) {
+                        assert_eq!(encoding.decompress_with_hint(&(-&x).as_bytes()), None);
+                    }
+                    assert_eq!(
+                        encoding.decompress_with_hint(&(&x + &FieldElement::ONE).as_bytes()),
+                        None
+                    );
+                }
+                None => {
+                    assert!(P.is_none());
+                    assert_eq!(encoding.decompress_with_hint(&[0u8; 32]), None);
+                    num_invalid += 1;
+                }
+            }
+        }
+        assert!(num_invalid > 0);
+    }
+
     /// Test that computing 1*basepoint gives the correct basepoint.
     #[cfg(feature = "precomputed-tables")]
     #[test]
@@ -1813,6 +2726,20 @@ mod test {
         );
     }
 
+    /// Test that multiplying with an EdwardsPointTable agrees with variable-base multiplication
+    #[test]
+    fn point_table_vs_variable_base_mul() {
+        let P = EdwardsPoint::mul_base(&A_SCALAR);
+        let table = EdwardsPointTable::create(&P);
+        for s in &[Scalar::ZERO, Scalar::ONE, -Scalar::ONE, A_SCALAR, B_SCALAR] {
+            assert_eq!(&table * s, P * s);
+            assert_eq!(s * &table, P * s);
+        }
+
+        let table = EdwardsPointTable::create(&EdwardsPoint::identity());
+        assert_eq!(&table * &A_SCALAR, EdwardsPoint::identity());
+    }
+
     /// Test that computing 2*basepoint is the same as basepoint.double()
     #[test]
     fn basepoint_mult_two_vs_basepoint2() {
@@ -2106,6 +3033,89 @@ mod test {
         }
     }
 
+    // Large enough for the serial backend to use batch-affine Pippenger
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn multiscalar_consistency_n_10000() {
+        multiscalar_consistency_iter(10000);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn multiscalar_consistency_parallel() {
+        use crate::backend::{
+            parallel_pippenger_optional_multiscalar_mul, pippenger_optional_multiscalar_mul,
+            PARALLEL_PIPPENGER_MIN_CHUNK,
+        };
+
+        // Use a fixed number of threads, so that the input is always split
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+
+        let mut rng = rand::thread_rng();
+        let n = 3 * PARALLEL_PIPPENGER_MIN_CHUNK + 17;
+        let xs = (0..n).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+        let check = xs.iter().map(|xi| xi * xi).sum::<Scalar>();
+        let Gs = xs.iter().map(EdwardsPoint::mul_base).collect::<Vec<_>>();
+
+        pool.install(|| {
+            let parallel =
+                parallel_pippenger_optional_multiscalar_mul(&xs, Gs.iter().copied().map(Some));
+            let serial = pippenger_optional_multiscalar_mul(&xs, Gs.iter().copied().map(Some));
+            assert_eq!(parallel, Some(EdwardsPoint::mul_base(&check)));
+            assert_eq!(parallel, serial);
+
+            // The public API takes the parallel path for inputs of this size
+            assert_eq!(
+                EdwardsPoint::vartime_multiscalar_mul(&xs, &Gs),
+                EdwardsPoint::mul_base(&check)
+            );
+
+            let mut points = Gs.iter().copied().map(Some).collect::<Vec<_>>();
+            points[n - 1] = None;
+            assert_eq!(
+                parallel_pippenger_optional_multiscalar_mul(&xs, points),
+                None
+            );
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn multiscalar_accumulator() {
+        let mut rng = rand::thread_rng();
+
+        let n = 1000;
+        let xs = (0..n).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+        let Gs = (0..n)
+            .map(|_| EdwardsPoint::mul_base(&Scalar::random(&mut rng)))
+            .collect::<Vec<_>>();
+        let expected = EdwardsPoint::vartime_multiscalar_mul(&xs, &Gs);
+
+        // Flush on every push, after a whole number of batches, and with a partial batch left
+        // over for finish()
+        for capacity in [
+            1,
+            100,
+            128,
+            n,
+            EdwardsMultiscalarAccumulator::DEFAULT_CAPACITY,
+        ] {
+            let mut accumulator = EdwardsMultiscalarAccumulator::with_capacity(capacity);
+            for (x, G) in xs.iter().zip(Gs.iter()) {
+                accumulator.push(*x, *G);
+            }
+            assert_eq!(accumulator.finish().compress(), expected.compress());
+        }
+
+        assert_eq!(
+            EdwardsMultiscalarAccumulator::new().finish(),
+            EdwardsPoint::identity()
+        );
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn vartime_precomputed_vs_nonprecomputed_multiscalar() {
@@ -2154,6 +3164,76 @@ mod test {
         assert_eq!(Q.compress(), R.compress());
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn vartime_multiscalar_short_scalars() {
+        use crate::traits::VartimeMultiscalarMul;
+
+        let mut rng = rand::thread_rng();
+
+        // Sizes which go through Straus, Pippenger and batch-affine Pippenger
+        for n in [16, 500, crate::backend::BATCH_AFFINE_PIPPENGER_THRESHOLD] {
+            let points = (0..n)
+                .map(|_| EdwardsPoint::mul_base(&Scalar::random(&mut rng)))
+                .collect::<Vec<_>>();
+            let scalars = (0..n)
+                .map(|_| Scalar::from(rng.next_u64()))
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                EdwardsPoint::vartime_multiscalar_mul(&scalars, &points).compress(),
+                EdwardsPoint::multiscalar_mul(&scalars, &points).compress()
+            );
+
+            // Mix full-size, 128-bit, 64-bit and zero scalars, so that only some terms
+            // reach the top digits
+            let mixed = (0..n)
+                .map(|i| match i % 4 {
+                    0 => Scalar::random(&mut rng),
+                    1 => {
+                        Scalar::from(u128::from(rng.next_u64()) << 64 | u128::from(rng.next_u64()))
+                    }
+                    2 => Scalar::from(rng.next_u64()),
+                    _ => Scalar::ZERO,
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                EdwardsPoint::vartime_multiscalar_mul(&mixed, &points).compress(),
+                EdwardsPoint::multiscalar_mul(&mixed, &points).compress()
+            );
+
+            let zeros = vec![Scalar::ZERO; n];
+            assert_eq!(
+                EdwardsPoint::vartime_multiscalar_mul(&zeros, &points),
+                EdwardsPoint::identity()
+            );
+
+            // A missing point must still fail, even if every scalar is zero
+            let mut optional_points = points.iter().copied().map(Some).collect::<Vec<_>>();
+            optional_points[n - 1] = None;
+            assert!(EdwardsPoint::optional_multiscalar_mul(&zeros, optional_points).is_none());
+        }
+
+        let static_points = (0..16)
+            .map(|_| EdwardsPoint::mul_base(&Scalar::random(&mut rng)))
+            .collect::<Vec<_>>();
+        let static_scalars = (0..16)
+            .map(|_| Scalar::from(rng.next_u64()))
+            .collect::<Vec<_>>();
+        let precomputation = VartimeEdwardsPrecomputation::new(static_points.iter());
+
+        assert_eq!(
+            precomputation
+                .vartime_multiscalar_mul(&static_scalars)
+                .compress(),
+            EdwardsPoint::multiscalar_mul(&static_scalars, &static_points).compress()
+        );
+        assert_eq!(
+            precomputation.vartime_multiscalar_mul(vec![Scalar::ZERO; 16]),
+            EdwardsPoint::identity()
+        );
+    }
+
     mod vartime {
         use super::super::*;
         use super::{A_SCALAR, A_TIMES_BASEPOINT, B_SCALAR, DOUBLE_SCALAR_MULT_RESULT};
@@ -2167,6 +3247,40 @@ mod test {
             assert_eq!(result.compress(), DOUBLE_SCALAR_MULT_RESULT);
         }
 
+        /// Test vartime_mul and vartime_mul_base vs ed25519.py
+        #[test]
+        fn vartime_mul_vs_ed25519py() {
+            let aB = constants::ED25519_BASEPOINT_POINT.vartime_mul(&A_SCALAR);
+            assert_eq!(aB.compress(), A_TIMES_BASEPOINT);
+
+            let aB = EdwardsPoint::vartime_mul_base(&A_SCALAR);
+            assert_eq!(aB.compress(), A_TIMES_BASEPOINT);
+        }
+
+        #[test]
+        fn vartime_mul_vs_consttime() {
+            let mut rng = rand::thread_rng();
+            let P = EdwardsPoint::mul_base(&Scalar::random(&mut rng));
+
+            let scalars = [
+                Scalar::ZERO,
+                Scalar::ONE,
+                -Scalar::ONE,
+                Scalar::from_bytes_mod_order([0xff; 32]),
+                Scalar::random(&mut rng),
+                Scalar::random(&mut rng),
+            ];
+            for s in &scalars {
+                assert_eq!(P.vartime_mul(s), P * s);
+                assert_eq!(EdwardsPoint::vartime_mul_base(s), EdwardsPoint::mul_base(s));
+            }
+
+            assert_eq!(
+                EdwardsPoint::identity().vartime_mul(&scalars[4]),
+                EdwardsPoint::identity()
+            );
+        }
+
         #[test]
         #[cfg(feature = "alloc")]
         fn multiscalar_mul_vs_ed25519py() {
@@ -2219,6 +3333,249 @@ mod test {
         assert_eq!(bp, constants::ED25519_BASEPOINT_POINT);
     }
 
+    /// Construct an `EdwardsPoint` from affine coordinates given as RFC 9380 hex strings.
+    fn point_from_rfc_hex(x: &str, y: &str) -> EdwardsPoint {
+        let x = fe_from_rfc_hex(x);
+        let y = fe_from_rfc_hex(y);
+        let P = EdwardsPoint {
+            X: x,
+            Y: y,
+            Z: FieldElement::ONE,
+            T: &x * &y,
+        };
+        assert!(P.is_valid());
+        P
+    }
+
+    /// Test vectors for `edwards25519_XMD:SHA-512_ELL2_RO_` from RFC 9380, appendix J.5.1,
+    /// of the form (msg, P.x, P.y).
+    #[cfg(feature = "digest")]
+    const RFC9380_HASH_TO_CURVE_KAT: &[(&[u8], &str, &str)] = &[
+        (
+            b"",
+            "3c3da6925a3c3c268448dcabb47ccde5439559d9599646a8260e47b1e4822fc6",
+            "09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21",
+        ),
+        (
+            b"abc",
+            "608040b42285cc0d72cbb3985c6b04c935370c7361f4b7fbdb1ae7f8c1a8ecad",
+            "1a8395b88338f22e435bbd301183e7f20a5f9de643f11882fb237f88268a5531",
+        ),
+        (
+            b"abcdef0123456789",
+            "6d7fabf47a2dc03fe7d47f7dddd21082c5fb8f86743cd020f3fb147d57161472",
+            "53060a3d140e7fbcda641ed3cf42c88a75411e648a1add71217f70ea8ec561a6",
+        ),
+        (
+            b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+            "5fb0b92acedd16f3bcb0ef83f5c7b7a9466b5f1e0d8d217421878ea3686f8524",
+            "2eca15e355fcfa39d2982f67ddb0eea138e2994f5956ed37b7f72eea5e89d2f7",
+        ),
+        (
+            b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "0efcfde5898a839b00997fbe40d2ebe950bc81181afbd5cd6b9618aa336c1e8c",
+            "6dc2fc04f266c5c27f236a80b14f92ccd051ef1ff027f26a07f8c0f327d8f995",
+        ),
+    ];
+
+    /// Test vectors for `edwards25519_XMD:SHA-512_ELL2_NU_` from RFC 9380, appendix J.5.2,
+    /// of the form (msg, P.x, P.y).
+    #[cfg(feature = "digest")]
+    const RFC9380_ENCODE_TO_CURVE_KAT: &[(&[u8], &str, &str)] = &[
+        (
+            b"",
+            "1ff2b70ecf862799e11b7ae744e3489aa058ce805dd323a936375a84695e76da",
+            "222e314d04a4d5725e9f2aff9fb2a6b69ef375a1214eb19021ceab2d687f0f9b",
+        ),
+        (
+            b"abc",
+            "5f13cc69c891d86927eb37bd4afc6672360007c63f68a33ab423a3aa040fd2a8",
+            "67732d50f9a26f73111dd1ed5dba225614e538599db58ba30aaea1f5c827fa42",
+        ),
+        (
+            b"abcdef0123456789",
+            "1dd2fefce934ecfd7aae6ec998de088d7dd03316aa1847198aecf699ba6613f1",
+            "2f8a6c24dd1adde73909cada6a4a137577b0f179d336685c4a955a0a8e1a86fb",
+        ),
+        (
+            b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+            "35fbdc5143e8a97afd3096f2b843e07df72e15bfca2eaf6879bf97c5d3362f73",
+            "2af6ff6ef5ebba128b0774f4296cb4c2279a074658b083b8dcca91f57a603450",
+        ),
+        (
+            b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "6e5e1f37e99345887fc12111575fc1c3e36df4b289b8759d23af14d774b66bff",
+            "2c90c3d39eb18ff291d33441b35f3262cdd307162cc97c31bfcc7a4245891a37",
+        ),
+    ];
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn hash_to_curve_rfc9380_test_vectors() {
+        let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+        for (i, (msg, x, y)) in RFC9380_HASH_TO_CURVE_KAT.iter().enumerate() {
+            let P = EdwardsPoint::hash_to_curve::<sha2::Sha512>(msg, dst);
+            assert_eq!(P, point_from_rfc_hex(x, y), "failed on test vector {}", i);
+            assert!(P.is_torsion_free());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn encode_to_curve_rfc9380_test_vectors() {
+        let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_";
+        for (i, (msg, x, y)) in RFC9380_ENCODE_TO_CURVE_KAT.iter().enumerate() {
+            let P = EdwardsPoint::encode_to_curve::<sha2::Sha512>(msg, dst);
+            assert_eq!(P, point_from_rfc_hex(x, y), "failed on test vector {}", i);
+            assert!(P.is_torsion_free());
+        }
+    }
+
+    /// Check the map to the curve, before cofactor clearing, against the intermediate values
+    /// (u, Q.x, Q.y) given in RFC 9380, appendix J.5.
+    #[test]
+    fn elligator_map_to_curve_rfc9380_test_vectors() {
+        let vectors = [
+            (
+                "03fef4813c8cb5f98c6eef88fae174e6e7d5380de2b007799ac7ee712d203f3a",
+                "6549118f65bb617b9e8b438decedc73c496eaed496806d3b2eb9ee60b88e09a7",
+                "7315bcc8cf47ed68048d22bad602c6680b3382a08c7c5d3f439a973fb4cf9feb",
+            ),
+            (
+                "780bdddd137290c8f589dc687795aafae35f6b674668d92bf92ae793e6a60c75",
+                "31dcfc5c58aa1bee6e760bf78cbe71c2bead8cebb2e397ece0f37a3da19c9ed2",
+                "7876d81474828d8a5928b50c82420b2bd0898d819e9550c5c82c39fc9bafa196",
+            ),
+            (
+                "7f3e7fb9428103ad7f52db32f9df32505d7b427d894c5093f7a0f0374a30641d",
+                "42836f691d05211ebc65ef8fcf01e0fb6328ec9c4737c26050471e50803022eb",
+                "22cb4aaa555e23bd460262d2130d6a3c9207aa8bbb85060928beb263d6d42a95",
+            ),
+            (
+                "09cfa30ad79bd59456594a0f5d3a76f6b71c6787b04de98be5cd201a556e253b",
+                "333e41b61c6dd43af220c1ac34a3663e1cf537f996bab50ab66e33c4bd8e4e19",
+                "51b6f178eb08c4a782c820e306b82c6e273ab22e258d972cd0c511787b2a3443",
+            ),
+        ];
+        for (u, x, y) in vectors.iter() {
+            let Q = EdwardsPoint::elligator_map_to_curve(&fe_from_rfc_hex(u));
+            assert!(Q.is_valid());
+            assert_eq!(Q, point_from_rfc_hex(x, y));
+        }
+    }
+
+    #[test]
+    fn elligator_map_to_curve_exceptional_input() {
+        // u = 0 maps to the Montgomery 2-torsion point (0, 0), which the rational map sends to
+        // the identity.
+        let Q = EdwardsPoint::elligator_map_to_curve(&FieldElement::ZERO);
+        assert!(Q.is_valid());
+        assert!(Q.is_identity());
+    }
+
+    #[test]
+    fn representative_roundtrip() {
+        let mut csprng = rand_core::OsRng;
+
+        for _ in 0..100 {
+            let mut r = [0u8; 32];
+            csprng.fill_bytes(&mut r);
+            let tweak = r[31];
+            let P = EdwardsPoint::from_representative(&r);
+            assert!(P.is_valid());
+
+            let r_prime = P.to_representative(tweak).unwrap();
+            assert_eq!(EdwardsPoint::from_representative(&r_prime), P);
+            assert_eq!(r_prime[31] & 0xc0, tweak & 0xc0);
+
+            // The u-coordinate agrees with the Montgomery map
+            assert_eq!(P.to_montgomery(), MontgomeryPoint::from_representative(&r));
+        }
+    }
+
+    #[test]
+    fn representative_exceptional_points() {
+        let minus_one = EdwardsPoint {
+            X: FieldElement::ZERO,
+            Y: FieldElement::MINUS_ONE,
+            Z: FieldElement::ONE,
+            T: FieldElement::ZERO,
+        };
+        assert!(bool::from(
+            EdwardsPoint::identity().to_representative(0).is_none()
+        ));
+        assert!(bool::from(minus_one.to_representative(0).is_none()));
+    }
+
+    #[test]
+    fn uniform_bytes_sq_roundtrip() {
+        let mut csprng = rand_core::OsRng;
+
+        for T in constants::EIGHT_TORSION.iter() {
+            let P = EdwardsPoint::mul_base(&Scalar::random(&mut csprng));
+            for P in [*T, P, P + T].iter() {
+                let bytes = P.to_uniform_bytes(&mut csprng);
+                assert_eq!(EdwardsPoint::from_uniform_bytes_sq(&bytes), *P);
+
+                // The encoding is randomized
+                assert_ne!(P.to_uniform_bytes(&mut csprng)[..], bytes[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn uniform_bytes_sq_high_bits() {
+        // The high bits of both representatives should be filled in
+        let mut csprng = rand_core::OsRng;
+        let P = constants::ED25519_BASEPOINT_POINT;
+
+        let mut seen = [[false; 4]; 2];
+        for _ in 0..64 {
+            let bytes = P.to_uniform_bytes(&mut csprng);
+            seen[0][(bytes[31] >> 6) as usize] = true;
+            seen[1][(bytes[63] >> 6) as usize] = true;
+        }
+        assert!(seen.iter().flatten().all(|s| *s));
+    }
+
+    #[test]
+    fn mul_base_clamped_dirty_low_order_component() {
+        let mut csprng = rand_core::OsRng;
+        let mut secret = [0u8; 32];
+        csprng.fill_bytes(&mut secret);
+        let peer = clamp_integer([0x42; 32]);
+
+        for k in 0..8 {
+            secret[0] = (secret[0] & !7) | k;
+            let clean = EdwardsPoint::mul_base_clamped(secret);
+            let dirty = EdwardsPoint::mul_base_clamped_dirty(secret);
+
+            // The difference has order exactly 8 when k is odd, and is the identity when k = 0
+            let torsion = dirty - clean;
+            assert!(torsion.mul_by_cofactor().is_identity());
+            assert_eq!(torsion.is_identity(), k == 0);
+            assert_eq!(torsion.mul_by_pow_2(2).is_identity(), k % 2 == 0);
+
+            // The X25519 shared secret is unchanged
+            assert_eq!(
+                dirty.to_montgomery().mul_clamped(peer),
+                clean.to_montgomery().mul_clamped(peer)
+            );
+        }
+    }
+
     ////////////////////////////////////////////////////////////
     // Signal tests from                                      //
     //     https://github.com/signalapp/libsignal-protocol-c/ //
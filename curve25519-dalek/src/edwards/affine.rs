@@ -0,0 +1,566 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Edwards points in affine coordinates.
+//!
+//! An [`AffineEdwardsPoint`] takes three quarters of the space of an [`EdwardsPoint`], and can be
+//! added to an `EdwardsPoint` with a mixed addition, which skips the multiplications by \\(Z\\)
+//! and by \\(2d\\) that a general point addition needs. This makes it a good fit for large
+//! tables of fixed points.
+//!
+//! Converting an `EdwardsPoint` to affine coordinates costs a field inversion, but
+//! [`EdwardsPoint::batch_to_affine`] shares a single inversion between any number of points.
+
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[cfg(feature = "group")]
+use group::{
+    cofactor::{CofactorCurve, CofactorCurveAffine},
+    Curve, GroupEncoding,
+};
+
+#[cfg(feature = "group")]
+use subtle::CtOption;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use super::{CompressedEdwardsY, EdwardsPoint};
+use crate::backend::serial::curve_models::AffineNielsPoint;
+use crate::constants;
+use crate::field::FieldElement;
+use crate::scalar::Scalar;
+use crate::traits::Identity;
+
+/// An `AffineEdwardsPoint` represents a point on the Edwards form of Curve25519 in affine
+/// coordinates \\((x, y)\\).
+///
+/// It also caches \\(2dxy\\), so that a mixed addition costs 7 field multiplications rather
+/// than the 9 of an addition of two `EdwardsPoint`s.
+///
+/// # Example
+///
+/// ```
+/// use curve25519_dalek::constants::ED25519_BASEPOINT_POINT as B;
+/// use curve25519_dalek::edwards::{AffineEdwardsPoint, EdwardsPoint};
+///
+/// let points = [B, B + B, B + B + B];
+/// let mut affine = [AffineEdwardsPoint::default(); 3];
+/// EdwardsPoint::batch_to_affine(&points, &mut affine);
+///
+/// // Mixed addition of an extended and an affine point
+/// assert_eq!(points[0] + affine[1], points[2]);
+/// assert_eq!(affine[2].compress(), points[2].compress());
+/// ```
+#[derive(Copy, Clone)]
+pub struct AffineEdwardsPoint {
+    pub(super) x: FieldElement,
+    pub(super) y: FieldElement,
+    xy2d: FieldElement,
+}
+
+impl AffineEdwardsPoint {
+    /// Construct the point \\((x, y)\\), computing its cached \\(2dxy\\).
+    fn from_xy(x: FieldElement, y: FieldElement) -> AffineEdwardsPoint {
+        let xy2d = &(&x * &y) * &constants::EDWARDS_D2;
+        AffineEdwardsPoint { x, y, xy2d }
+    }
+
+    /// Convert this point to extended twisted Edwards coordinates.
+    pub fn to_edwards(&self) -> EdwardsPoint {
+        EdwardsPoint {
+            X: self.x,
+            Y: self.y,
+            Z: FieldElement::ONE,
+            T: &self.x * &self.y,
+        }
+    }
+
+    /// Compress this point to `CompressedEdwardsY` format.
+    ///
+    /// Unlike [`EdwardsPoint::compress`], this needs no field inversion.
+    pub fn compress(&self) -> CompressedEdwardsY {
+        let mut s = self.y.as_bytes();
+        s[31] ^= self.x.is_negative().unwrap_u8() << 7;
+        CompressedEdwardsY(s)
+    }
+
+    /// Convert to the precomputed form used for mixed addition.
+    fn as_affine_niels(&self) -> AffineNielsPoint {
+        AffineNielsPoint {
+            y_plus_x: &self.y + &self.x,
+            y_minus_x: &self.y - &self.x,
+            xy2d: self.xy2d,
+        }
+    }
+}
+
+impl EdwardsPoint {
+    /// Convert this point to affine coordinates.
+    ///
+    /// This costs a field inversion. To convert many points, use
+    /// [`EdwardsPoint::batch_to_affine`] instead.
+    pub fn to_affine(&self) -> AffineEdwardsPoint {
+        let recip = self.Z.invert();
+        AffineEdwardsPoint::from_xy(&self.X * &recip, &self.Y * &recip)
+    }
+
+    /// Convert the points in `points` to affine coordinates, writing the results to `out`.
+    ///
+    /// This shares a single field inversion between all of the points, using Montgomery's
+    /// trick, and does not allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `out` have different lengths.
+    pub fn batch_to_affine(points: &[EdwardsPoint], out: &mut [AffineEdwardsPoint]) {
+        assert_eq!(points.len(), out.len());
+
+        // Use the x coordinates of the output to hold the running products Z_0 ... Z_{i-1}.
+        // Every valid point has Z nonzero, so there are no zeros to skip.
+        let mut acc = FieldElement::ONE;
+        for (P, A) in points.iter().zip(out.iter_mut()) {
+            A.x = acc;
+            acc = &acc * &P.Z;
+        }
+
+        acc = acc.invert();
+
+        for (P, A) in points.iter().zip(out.iter_mut()).rev() {
+            let recip = &acc * &A.x;
+            acc = &acc * &P.Z;
+            *A = AffineEdwardsPoint::from_xy(&P.X * &recip, &P.Y * &recip);
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Constructors, equality, and conditional selection
+// ------------------------------------------------------------------------
+
+impl Identity for AffineEdwardsPoint {
+    fn identity() -> AffineEdwardsPoint {
+        AffineEdwardsPoint {
+            x: FieldElement::ZERO,
+            y: FieldElement::ONE,
+            xy2d: FieldElement::ZERO,
+        }
+    }
+}
+
+impl Default for AffineEdwardsPoint {
+    fn default() -> AffineEdwardsPoint {
+        <AffineEdwardsPoint as Identity>::identity()
+    }
+}
+
+impl ConstantTimeEq for AffineEdwardsPoint {
+    fn ct_eq(&self, other: &AffineEdwardsPoint) -> Choice {
+        self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y)
+    }
+}
+
+impl PartialEq for AffineEdwardsPoint {
+    fn eq(&self, other: &AffineEdwardsPoint) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for AffineEdwardsPoint {}
+
+impl ConditionallySelectable for AffineEdwardsPoint {
+    fn conditional_select(
+        a: &AffineEdwardsPoint,
+        b: &AffineEdwardsPoint,
+        choice: Choice,
+    ) -> AffineEdwardsPoint {
+        AffineEdwardsPoint {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            xy2d: FieldElement::conditional_select(&a.xy2d, &b.xy2d, choice),
+        }
+    }
+}
+
+impl From<AffineEdwardsPoint> for EdwardsPoint {
+    fn from(P: AffineEdwardsPoint) -> EdwardsPoint {
+        P.to_edwards()
+    }
+}
+
+impl From<EdwardsPoint> for AffineEdwardsPoint {
+    fn from(P: EdwardsPoint) -> AffineEdwardsPoint {
+        P.to_affine()
+    }
+}
+
+impl Debug for AffineEdwardsPoint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "AffineEdwardsPoint{{\n\tx: {:?},\n\ty: {:?}\n}}",
+            &self.x, &self.y
+        )
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for AffineEdwardsPoint {
+    /// Reset this `AffineEdwardsPoint` to the identity element.
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y = FieldElement::ONE;
+        self.xy2d.zeroize();
+    }
+}
+
+// ------------------------------------------------------------------------
+// Mixed addition and subtraction
+// ------------------------------------------------------------------------
+
+impl<'a> Add<&'a AffineEdwardsPoint> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn add(self, other: &'a AffineEdwardsPoint) -> EdwardsPoint {
+        (self + &other.as_affine_niels()).as_extended()
+    }
+}
+
+define_add_variants!(
+    LHS = EdwardsPoint,
+    RHS = AffineEdwardsPoint,
+    Output = EdwardsPoint
+);
+
+impl AddAssign<&AffineEdwardsPoint> for EdwardsPoint {
+    fn add_assign(&mut self, rhs: &AffineEdwardsPoint) {
+        *self = (self as &EdwardsPoint) + rhs;
+    }
+}
+
+define_add_assign_variants!(LHS = EdwardsPoint, RHS = AffineEdwardsPoint);
+
+impl<'a> Sub<&'a AffineEdwardsPoint> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn sub(self, other: &'a AffineEdwardsPoint) -> EdwardsPoint {
+        (self - &other.as_affine_niels()).as_extended()
+    }
+}
+
+define_sub_variants!(
+    LHS = EdwardsPoint,
+    RHS = AffineEdwardsPoint,
+    Output = EdwardsPoint
+);
+
+impl SubAssign<&AffineEdwardsPoint> for EdwardsPoint {
+    fn sub_assign(&mut self, rhs: &AffineEdwardsPoint) {
+        *self = (self as &EdwardsPoint) - rhs;
+    }
+}
+
+define_sub_assign_variants!(LHS = EdwardsPoint, RHS = AffineEdwardsPoint);
+
+impl Neg for &AffineEdwardsPoint {
+    type Output = AffineEdwardsPoint;
+    fn neg(self) -> AffineEdwardsPoint {
+        AffineEdwardsPoint {
+            x: -&self.x,
+            y: self.y,
+            xy2d: -&self.xy2d,
+        }
+    }
+}
+
+impl Neg for AffineEdwardsPoint {
+    type Output = AffineEdwardsPoint;
+    fn neg(self) -> AffineEdwardsPoint {
+        -&self
+    }
+}
+
+// ------------------------------------------------------------------------
+// Scalar multiplication
+// ------------------------------------------------------------------------
+
+impl<'a> Mul<&'a Scalar> for &AffineEdwardsPoint {
+    type Output = EdwardsPoint;
+    fn mul(self, scalar: &'a Scalar) -> EdwardsPoint {
+        self.to_edwards() * scalar
+    }
+}
+
+define_mul_variants!(
+    LHS = AffineEdwardsPoint,
+    RHS = Scalar,
+    Output = EdwardsPoint
+);
+
+impl<'a> Mul<&'a AffineEdwardsPoint> for &Scalar {
+    type Output = EdwardsPoint;
+    fn mul(self, point: &'a AffineEdwardsPoint) -> EdwardsPoint {
+        point * self
+    }
+}
+
+define_mul_variants!(
+    LHS = Scalar,
+    RHS = AffineEdwardsPoint,
+    Output = EdwardsPoint
+);
+
+// ------------------------------------------------------------------------
+// Serde support
+// ------------------------------------------------------------------------
+// Serializes to and from the same compressed format as `EdwardsPoint`.
+
+#[cfg(feature = "serde")]
+use serde::de::Visitor;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+impl Serialize for AffineEdwardsPoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeTuple;
+        let mut tup = serializer.serialize_tuple(32)?;
+        for byte in self.compress().as_bytes().iter() {
+            tup.serialize_element(byte)?;
+        }
+        tup.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for AffineEdwardsPoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AffineEdwardsPointVisitor;
+
+        impl<'de> Visitor<'de> for AffineEdwardsPointVisitor {
+            type Value = AffineEdwardsPoint;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str("a valid point in Edwards y + sign format")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<AffineEdwardsPoint, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut bytes = [0u8; 32];
+                #[allow(clippy::needless_range_loop)]
+                for i in 0..32 {
+                    bytes[i] = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(i, &"expected 32 bytes"))?;
+                }
+                CompressedEdwardsY(bytes)
+                    .decompress()
+                    .map(|P| P.to_affine())
+                    .ok_or_else(|| serde::de::Error::custom("decompression failed"))
+            }
+        }
+
+        deserializer.deserialize_tuple(32, AffineEdwardsPointVisitor)
+    }
+}
+
+// ------------------------------------------------------------------------
+// group traits
+// ------------------------------------------------------------------------
+
+#[cfg(feature = "group")]
+impl Curve for EdwardsPoint {
+    type AffineRepr = AffineEdwardsPoint;
+
+    fn batch_normalize(p: &[Self], q: &mut [Self::AffineRepr]) {
+        EdwardsPoint::batch_to_affine(p, q);
+    }
+
+    fn to_affine(&self) -> Self::AffineRepr {
+        EdwardsPoint::to_affine(self)
+    }
+}
+
+#[cfg(feature = "group")]
+impl CofactorCurve for EdwardsPoint {
+    type Affine = AffineEdwardsPoint;
+}
+
+#[cfg(feature = "group")]
+impl GroupEncoding for AffineEdwardsPoint {
+    type Repr = [u8; 32];
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        EdwardsPoint::from_bytes(bytes).map(|P| P.to_affine())
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        // Just use the checked API; there are no checks we can skip.
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.compress().to_bytes()
+    }
+}
+
+#[cfg(feature = "group")]
+impl CofactorCurveAffine for AffineEdwardsPoint {
+    type Scalar = Scalar;
+    type Curve = EdwardsPoint;
+
+    fn identity() -> Self {
+        Identity::identity()
+    }
+
+    fn generator() -> Self {
+        constants::ED25519_BASEPOINT_POINT.to_affine()
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.ct_eq(&Identity::identity())
+    }
+
+    fn to_curve(&self) -> Self::Curve {
+        self.to_edwards()
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::traits::{IsIdentity, ValidityCheck};
+
+    use rand_core::OsRng;
+
+    /// Some random points, the eight torsion points, and a random point plus torsion.
+    fn test_points() -> [EdwardsPoint; 24] {
+        let mut points = [EdwardsPoint::identity(); 24];
+        for P in points[..8].iter_mut() {
+            *P = EdwardsPoint::mul_base(&Scalar::random(&mut OsRng));
+        }
+        points[8..16].copy_from_slice(&constants::EIGHT_TORSION);
+        for i in 16..24 {
+            points[i] = points[i - 16] + constants::EIGHT_TORSION[i - 16];
+        }
+        points
+    }
+
+    #[test]
+    fn identity_conversion() {
+        assert_eq!(
+            <AffineEdwardsPoint as Identity>::identity().to_edwards(),
+            EdwardsPoint::identity()
+        );
+        assert!(IsIdentity::is_identity(
+            &EdwardsPoint::identity().to_affine()
+        ));
+    }
+
+    #[test]
+    fn basepoint_roundtrip() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let B_affine = B.to_affine();
+        assert_eq!(B_affine.to_edwards(), B);
+        assert_eq!(B_affine.compress(), constants::ED25519_BASEPOINT_COMPRESSED);
+        assert!(B_affine.to_edwards().is_valid());
+    }
+
+    #[test]
+    fn batch_to_affine_matches_to_affine() {
+        let points = test_points();
+        let mut affine = [AffineEdwardsPoint::default(); 24];
+        EdwardsPoint::batch_to_affine(&points, &mut affine);
+        for (P, A) in points.iter().zip(affine.iter()) {
+            assert_eq!(*A, P.to_affine());
+            assert_eq!(A.compress(), P.compress());
+        }
+
+        EdwardsPoint::batch_to_affine(&[], &mut []);
+    }
+
+    #[test]
+    #[should_panic]
+    fn batch_to_affine_length_mismatch() {
+        EdwardsPoint::batch_to_affine(&[EdwardsPoint::identity()], &mut []);
+    }
+
+    #[test]
+    fn mixed_addition_matches_extended() {
+        let points = test_points();
+        for P in points.iter() {
+            for Q in points.iter() {
+                let Q_affine = Q.to_affine();
+                assert_eq!(P + Q_affine, P + Q);
+                assert_eq!(P - Q_affine, P - Q);
+                assert_eq!(P + (-Q_affine), P - Q);
+
+                let mut R = *P;
+                R += Q_affine;
+                R -= &Q_affine;
+                assert_eq!(R, *P);
+            }
+            assert_eq!(-P.to_affine(), (-P).to_affine());
+        }
+    }
+
+    #[test]
+    fn scalar_mul_matches_extended() {
+        let s = Scalar::random(&mut OsRng);
+        for P in test_points().iter() {
+            assert_eq!(P.to_affine() * s, P * s);
+            assert_eq!(s * P.to_affine(), P * s);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_bincode_roundtrip() {
+        for P in test_points().iter() {
+            let A = P.to_affine();
+            let encoded = bincode::serialize(&A).unwrap();
+            assert_eq!(encoded, bincode::serialize(P).unwrap());
+            let decoded: AffineEdwardsPoint = bincode::deserialize(&encoded).unwrap();
+            assert_eq!(decoded, A);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "group")]
+    fn group_curve() {
+        use group::Group;
+
+        let points = test_points();
+        let mut affine = [AffineEdwardsPoint::default(); 24];
+        Curve::batch_normalize(&points, &mut affine);
+        for (P, A) in points.iter().zip(affine.iter()) {
+            assert_eq!(Curve::to_affine(P), *A);
+            assert_eq!(A.to_curve(), *P);
+            assert_eq!(
+                AffineEdwardsPoint::from_bytes(&GroupEncoding::to_bytes(A)).unwrap(),
+                *A
+            );
+        }
+        assert_eq!(
+            <AffineEdwardsPoint as CofactorCurveAffine>::generator().to_curve(),
+            EdwardsPoint::generator()
+        );
+        let identity = <AffineEdwardsPoint as CofactorCurveAffine>::identity();
+        assert!(bool::from(CofactorCurveAffine::is_identity(&identity)));
+    }
+}
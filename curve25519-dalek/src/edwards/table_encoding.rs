@@ -0,0 +1,571 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Byte encodings of precomputed tables.
+//!
+//! Building a basepoint table or a multiscalar precomputation takes many point additions and
+//! field inversions.  Encoding the result lets it be cached on disk, or embedded in a binary with
+//! `include_bytes!`, and loaded at a fraction of the cost.
+//!
+//! # Format
+//!
+//! Every encoding starts with a 6-byte header: the magic bytes `c25t`, the format version
+//! (currently `1`), and a byte identifying the kind of table.  Each table entry is a point
+//! \\(P = (x, y)\\) stored as the canonical encodings of the three field elements
+//! \\((y + x, y - x, 2dxy)\\), for 96 bytes per entry.
+//!
+//! * A basepoint table of radix \\(2\^w\\) has kind \\(w\\).  The header is followed by the
+//!   compressed basepoint \\(B\\), then the 32 lookup tables in order, where table \\(i\\)
+//!   holds \\([1, 2, \ldots, 2\^{w-1}] \cdot 2\^{2wi} B\\).
+//! * A [`VartimeEdwardsPrecomputation`](super::VartimeEdwardsPrecomputation) has kind `0x80`.
+//!   The header is followed by the number \\(n\\) of static points as a little-endian `u32`,
+//!   then for each static point \\(P\\), its compressed encoding followed by the odd multiples
+//!   \\([1, 3, 5, \ldots, 127] \cdot P\\).
+//!
+//! # Integrity checks
+//!
+//! Decoding rejects encodings with the wrong length, magic, version or kind, entries whose
+//! field elements are not canonical or which are not points on the curve, and tables whose
+//! first entry does not match the stored point.  These checks catch corrupted or mismatched
+//! data, but decoding does not recompute the table, so a table must still come from a trusted
+//! source.
+
+#![allow(non_snake_case)]
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{coordinate_from_bytes, CompressedEdwardsY, EdwardsPoint};
+use crate::backend::serial::curve_models::AffineNielsPoint;
+use crate::constants;
+use crate::traits::{Identity, ValidityCheck};
+
+#[cfg(feature = "precomputed-tables")]
+use super::{
+    EdwardsBasepointTable, EdwardsBasepointTableRadix128, EdwardsBasepointTableRadix256,
+    EdwardsBasepointTableRadix32, EdwardsBasepointTableRadix64,
+};
+#[cfg(all(feature = "precomputed-tables", feature = "alloc"))]
+use crate::traits::BasepointTable;
+
+#[cfg(feature = "alloc")]
+use super::VartimeEdwardsPrecomputation;
+#[cfg(feature = "alloc")]
+use crate::window::NafLookupTable8;
+
+const MAGIC: [u8; 4] = *b"c25t";
+const VERSION: u8 = 1;
+const HEADER_LENGTH: usize = 6;
+const ENTRY_LENGTH: usize = 96;
+
+#[cfg(feature = "alloc")]
+const KIND_VARTIME_PRECOMPUTATION: u8 = 0x80;
+
+/// The reason an encoded precomputed table was rejected.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TableEncodingError {
+    /// The encoding has the wrong length for its kind of table.
+    InvalidLength,
+    /// The encoding does not start with the magic bytes of a table encoding.
+    InvalidMagic,
+    /// The encoding uses an unsupported format version.
+    UnsupportedVersion,
+    /// The encoding is of a different kind of table.
+    WrongKind,
+    /// An entry is not a canonically-encoded point on the curve.
+    InvalidEntry,
+    /// The stored point does not match the first entry of its table.
+    PointMismatch,
+    /// The stored basepoint is not valid for this kind of table.
+    InvalidBasepoint,
+}
+
+impl Display for TableEncodingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TableEncodingError::InvalidLength => f.write_str("table encoding has the wrong length"),
+            TableEncodingError::InvalidMagic => f.write_str("not a table encoding"),
+            TableEncodingError::UnsupportedVersion => {
+                f.write_str("unsupported table encoding version")
+            }
+            TableEncodingError::WrongKind => f.write_str("table encoding is of the wrong kind"),
+            TableEncodingError::InvalidEntry => f.write_str("table entry is not a valid point"),
+            TableEncodingError::PointMismatch => {
+                f.write_str("stored point does not match the table")
+            }
+            TableEncodingError::InvalidBasepoint => {
+                f.write_str("stored basepoint is not valid for this table")
+            }
+        }
+    }
+}
+
+/// Append the header for a table of the given kind.
+#[cfg(feature = "alloc")]
+fn write_header(out: &mut Vec<u8>, kind: u8) {
+    out.extend_from_slice(&MAGIC);
+    out.push(VERSION);
+    out.push(kind);
+}
+
+/// Check the header, and return the rest of the encoding.
+fn read_header(bytes: &[u8], kind: u8) -> Result<&[u8], TableEncodingError> {
+    if bytes.len() < HEADER_LENGTH {
+        return Err(TableEncodingError::InvalidLength);
+    }
+    if bytes[0..4] != MAGIC {
+        return Err(TableEncodingError::InvalidMagic);
+    }
+    if bytes[4] != VERSION {
+        return Err(TableEncodingError::UnsupportedVersion);
+    }
+    if bytes[5] != kind {
+        return Err(TableEncodingError::WrongKind);
+    }
+    Ok(&bytes[HEADER_LENGTH..])
+}
+
+/// Decode a stored point, which must be the first entry of its table.
+fn read_point(bytes: &[u8], first_entry: &AffineNielsPoint) -> Result<(), TableEncodingError> {
+    let mut compressed = CompressedEdwardsY::default();
+    compressed.0.copy_from_slice(bytes);
+    let P = compressed
+        .decompress()
+        .ok_or(TableEncodingError::PointMismatch)?;
+
+    if (&EdwardsPoint::identity() + first_entry).as_extended() == P {
+        Ok(())
+    } else {
+        Err(TableEncodingError::PointMismatch)
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn write_entry(out: &mut Vec<u8>, entry: &AffineNielsPoint) {
+    out.extend_from_slice(&entry.y_plus_x.as_bytes());
+    out.extend_from_slice(&entry.y_minus_x.as_bytes());
+    out.extend_from_slice(&entry.xy2d.as_bytes());
+}
+
+/// Decode an entry, checking that it is a point on the curve.
+fn read_entry(bytes: &[u8]) -> Result<AffineNielsPoint, TableEncodingError> {
+    let field = |i: usize| {
+        coordinate_from_bytes(&bytes[32 * i..32 * (i + 1)])
+            .map_err(|_| TableEncodingError::InvalidEntry)
+    };
+    let entry = AffineNielsPoint {
+        y_plus_x: field(0)?,
+        y_minus_x: field(1)?,
+        xy2d: field(2)?,
+    };
+
+    // Adding the entry to the identity only uses y + x and y - x, so check that (x, y) is on the
+    // curve, and separately that 2 * (2dxy) = d * ((y + x)^2 - (y - x)^2).
+    let P = (&EdwardsPoint::identity() + &entry).as_extended();
+    let sum = &entry.y_plus_x + &entry.y_minus_x;
+    let difference = &entry.y_plus_x - &entry.y_minus_x;
+    let xy4d = &constants::EDWARDS_D * &(&sum * &difference);
+
+    if P.is_valid() && &entry.xy2d + &entry.xy2d == xy4d {
+        Ok(entry)
+    } else {
+        Err(TableEncodingError::InvalidEntry)
+    }
+}
+
+#[cfg(feature = "precomputed-tables")]
+macro_rules! impl_basepoint_table_encoding {
+    (Name = $name:ident, Radix = $radix:expr) => {
+        impl $name {
+            /// Encode this table, so that it can be loaded with
+            /// [`from_bytes`](Self::from_bytes) instead of being recomputed.
+            ///
+            /// The encoding is versioned, and stores the basepoint so that loading can check
+            /// it against the table.
+            #[cfg(feature = "alloc")]
+            pub fn to_bytes(&self) -> Vec<u8> {
+                let entries = self.0.iter().flat_map(|table| table.0.iter());
+
+                let mut out = Vec::with_capacity(
+                    HEADER_LENGTH + 32 + ENTRY_LENGTH * self.0.len() * self.0[0].0.len(),
+                );
+                write_header(&mut out, $radix);
+                out.extend_from_slice(self.basepoint().compress().as_bytes());
+                for entry in entries {
+                    write_entry(&mut out, entry);
+                }
+                out
+            }
+
+            /// Decode a table produced by [`to_bytes`](Self::to_bytes).
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the encoding is malformed, is of a different kind of table,
+            /// contains an entry which is not a point on the curve, or stores a basepoint which
+            /// does not match the table.
+            pub fn from_bytes(bytes: &[u8]) -> Result<$name, TableEncodingError> {
+                let mut table = $name(Default::default());
+                let entries_per_table = table.0[0].0.len();
+
+                let body = read_header(bytes, $radix)?;
+                if body.len() != 32 + ENTRY_LENGTH * 32 * entries_per_table {
+                    return Err(TableEncodingError::InvalidLength);
+                }
+
+                let mut chunks = body[32..].chunks(ENTRY_LENGTH);
+                for lookup_table in table.0.iter_mut() {
+                    for (entry, chunk) in lookup_table.0.iter_mut().zip(&mut chunks) {
+                        *entry = read_entry(chunk)?;
+                    }
+                }
+
+                read_point(&body[..32], &table.0[0].0[0])?;
+                Ok(table)
+            }
+        }
+
+        #[cfg(all(feature = "serde", feature = "alloc"))]
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_bytes(&self.to_bytes())
+            }
+        }
+
+        #[cfg(all(feature = "serde", feature = "alloc"))]
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_bytes(TableVisitor {
+                    expecting: concat!("an encoded ", stringify!($name)),
+                    decode: $name::from_bytes,
+                })
+            }
+        }
+    };
+}
+
+#[cfg(feature = "precomputed-tables")]
+impl_basepoint_table_encoding! {Name = EdwardsBasepointTable, Radix = 4}
+#[cfg(feature = "precomputed-tables")]
+impl_basepoint_table_encoding! {Name = EdwardsBasepointTableRadix32, Radix = 5}
+#[cfg(feature = "precomputed-tables")]
+impl_basepoint_table_encoding! {Name = EdwardsBasepointTableRadix64, Radix = 6}
+#[cfg(feature = "precomputed-tables")]
+impl_basepoint_table_encoding! {Name = EdwardsBasepointTableRadix128, Radix = 7}
+#[cfg(feature = "precomputed-tables")]
+impl_basepoint_table_encoding! {Name = EdwardsBasepointTableRadix256, Radix = 8}
+
+#[cfg(feature = "alloc")]
+impl VartimeEdwardsPrecomputation {
+    /// Encode this precomputation, so that it can be loaded with
+    /// [`from_bytes`](Self::from_bytes) instead of being recomputed.
+    ///
+    /// The encoding is versioned, and stores each static point so that loading can check it
+    /// against its table.  It does not depend on the backend in use, so it can be loaded on a
+    /// machine with different CPU features.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let tables = self.0.to_affine_tables();
+
+        let mut out =
+            Vec::with_capacity(HEADER_LENGTH + 4 + tables.len() * (32 + ENTRY_LENGTH * 64));
+        write_header(&mut out, KIND_VARTIME_PRECOMPUTATION);
+        out.extend_from_slice(&(tables.len() as u32).to_le_bytes());
+        for table in tables.iter() {
+            let P = (&EdwardsPoint::identity() + &table.0[0]).as_extended();
+            out.extend_from_slice(P.compress().as_bytes());
+            for entry in table.0.iter() {
+                write_entry(&mut out, entry);
+            }
+        }
+        out
+    }
+
+    /// Decode a precomputation produced by [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the encoding is malformed, is of a different kind of table, contains
+    /// an entry which is not a point on the curve, or stores a static point which does not match
+    /// its table.
+    pub fn from_bytes(bytes: &[u8]) -> Result<VartimeEdwardsPrecomputation, TableEncodingError> {
+        let body = read_header(bytes, KIND_VARTIME_PRECOMPUTATION)?;
+        if body.len() < 4 {
+            return Err(TableEncodingError::InvalidLength);
+        }
+        let mut count = [0u8; 4];
+        count.copy_from_slice(&body[..4]);
+        let count = u32::from_le_bytes(count) as usize;
+
+        let table_length = 32 + ENTRY_LENGTH * 64;
+        if Some(body.len() - 4) != count.checked_mul(table_length) {
+            return Err(TableEncodingError::InvalidLength);
+        }
+
+        let tables = body[4..]
+            .chunks(table_length)
+            .map(|chunk| {
+                let mut table = NafLookupTable8([AffineNielsPoint::identity(); 64]);
+                for (entry, bytes) in table.0.iter_mut().zip(chunk[32..].chunks(ENTRY_LENGTH)) {
+                    *entry = read_entry(bytes)?;
+                }
+                read_point(&chunk[..32], &table.0[0])?;
+                Ok(table)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(VartimeEdwardsPrecomputation(
+            crate::backend::VartimePrecomputedStraus::from_affine_tables(tables),
+        ))
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+impl Serialize for VartimeEdwardsPrecomputation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+impl<'de> Deserialize<'de> for VartimeEdwardsPrecomputation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(TableVisitor {
+            expecting: "an encoded VartimeEdwardsPrecomputation",
+            decode: VartimeEdwardsPrecomputation::from_bytes,
+        })
+    }
+}
+
+/// Deserialize a table from bytes, or from a sequence of bytes for formats without a byte
+/// string type.
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub(crate) struct TableVisitor<T> {
+    pub(crate) expecting: &'static str,
+    pub(crate) decode: fn(&[u8]) -> Result<T, TableEncodingError>,
+}
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+impl<'de, T> Visitor<'de> for TableVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_bytes<E>(self, bytes: &[u8]) -> Result<T, E>
+    where
+        E: serde::de::Error,
+    {
+        (self.decode)(bytes).map_err(E::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<T, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        // Don't trust the size hint for a large allocation
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(1 << 16));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+#[cfg(all(test, feature = "alloc", feature = "precomputed-tables"))]
+mod test {
+    use super::*;
+
+    use crate::scalar::Scalar;
+    use crate::traits::VartimePrecomputedMultiscalarMul;
+
+    use rand_core::OsRng;
+
+    fn check_basepoint_table_roundtrip<T>(
+        to_bytes: fn(&T) -> Vec<u8>,
+        from_bytes: fn(&[u8]) -> Result<T, TableEncodingError>,
+    ) where
+        T: BasepointTable<Point = EdwardsPoint>,
+    {
+        let B = EdwardsPoint::mul_base(&Scalar::random(&mut OsRng));
+        // Only keep one table on the stack at a time, since the larger ones are several hundred KB
+        let bytes = to_bytes(&T::create(&B));
+        let decoded = from_bytes(&bytes).unwrap();
+        assert_eq!(to_bytes(&decoded), bytes);
+        assert_eq!(decoded.basepoint(), B);
+
+        let s = Scalar::random(&mut OsRng);
+        assert_eq!(decoded.mul_base(&s), B * s);
+    }
+
+    #[test]
+    fn basepoint_table_roundtrip() {
+        check_basepoint_table_roundtrip(
+            EdwardsBasepointTable::to_bytes,
+            EdwardsBasepointTable::from_bytes,
+        );
+        check_basepoint_table_roundtrip(
+            EdwardsBasepointTableRadix32::to_bytes,
+            EdwardsBasepointTableRadix32::from_bytes,
+        );
+        check_basepoint_table_roundtrip(
+            EdwardsBasepointTableRadix64::to_bytes,
+            EdwardsBasepointTableRadix64::from_bytes,
+        );
+        check_basepoint_table_roundtrip(
+            EdwardsBasepointTableRadix128::to_bytes,
+            EdwardsBasepointTableRadix128::from_bytes,
+        );
+        check_basepoint_table_roundtrip(
+            EdwardsBasepointTableRadix256::to_bytes,
+            EdwardsBasepointTableRadix256::from_bytes,
+        );
+    }
+
+    #[test]
+    fn basepoint_table_rejects_malformed_encodings() {
+        let bytes = constants::ED25519_BASEPOINT_TABLE.to_bytes();
+
+        assert_eq!(
+            EdwardsBasepointTable::from_bytes(&bytes[..bytes.len() - 1]).err(),
+            Some(TableEncodingError::InvalidLength)
+        );
+        assert_eq!(
+            EdwardsBasepointTable::from_bytes(&bytes[..3]).err(),
+            Some(TableEncodingError::InvalidLength)
+        );
+
+        let mut corrupted = bytes.clone();
+        corrupted[0] ^= 1;
+        assert_eq!(
+            EdwardsBasepointTable::from_bytes(&corrupted).err(),
+            Some(TableEncodingError::InvalidMagic)
+        );
+
+        let mut corrupted = bytes.clone();
+        corrupted[4] = VERSION + 1;
+        assert_eq!(
+            EdwardsBasepointTable::from_bytes(&corrupted).err(),
+            Some(TableEncodingError::UnsupportedVersion)
+        );
+
+        assert_eq!(
+            EdwardsBasepointTableRadix32::from_bytes(&bytes).err(),
+            Some(TableEncodingError::WrongKind)
+        );
+        assert_eq!(
+            VartimeEdwardsPrecomputation::from_bytes(&bytes).err(),
+            Some(TableEncodingError::WrongKind)
+        );
+    }
+
+    #[test]
+    fn basepoint_table_rejects_invalid_entries() {
+        let bytes = constants::ED25519_BASEPOINT_TABLE.to_bytes();
+        let entries = HEADER_LENGTH + 32;
+
+        // A change to y + x moves the entry off the curve
+        let mut corrupted = bytes.clone();
+        corrupted[entries + 5 * ENTRY_LENGTH] ^= 1;
+        assert_eq!(
+            EdwardsBasepointTable::from_bytes(&corrupted).err(),
+            Some(TableEncodingError::InvalidEntry)
+        );
+
+        // A change to 2dxy is caught separately
+        let mut corrupted = bytes.clone();
+        corrupted[entries + 5 * ENTRY_LENGTH + 64] ^= 1;
+        assert_eq!(
+            EdwardsBasepointTable::from_bytes(&corrupted).err(),
+            Some(TableEncodingError::InvalidEntry)
+        );
+
+        // Non-canonical field element encodings are rejected
+        let mut corrupted = bytes;
+        corrupted[entries..entries + 32].copy_from_slice(&[0xff; 32]);
+        assert_eq!(
+            EdwardsBasepointTable::from_bytes(&corrupted).err(),
+            Some(TableEncodingError::InvalidEntry)
+        );
+    }
+
+    #[test]
+    fn basepoint_table_rejects_mismatched_basepoint() {
+        let mut bytes = constants::ED25519_BASEPOINT_TABLE.to_bytes();
+        let B2 = constants::ED25519_BASEPOINT_POINT + constants::ED25519_BASEPOINT_POINT;
+        bytes[HEADER_LENGTH..HEADER_LENGTH + 32].copy_from_slice(B2.compress().as_bytes());
+
+        assert_eq!(
+            EdwardsBasepointTable::from_bytes(&bytes).err(),
+            Some(TableEncodingError::PointMismatch)
+        );
+    }
+
+    #[test]
+    fn vartime_precomputation_roundtrip() {
+        let static_points: Vec<EdwardsPoint> = (0..3)
+            .map(|_| EdwardsPoint::mul_base(&Scalar::random(&mut OsRng)))
+            .collect();
+        let static_scalars: Vec<Scalar> = (0..3).map(|_| Scalar::random(&mut OsRng)).collect();
+        let precomputation = VartimeEdwardsPrecomputation::new(static_points.iter());
+
+        let bytes = precomputation.to_bytes();
+        let decoded = VartimeEdwardsPrecomputation::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.to_bytes(), bytes);
+        assert_eq!(
+            decoded.vartime_multiscalar_mul(static_scalars.iter()),
+            precomputation.vartime_multiscalar_mul(static_scalars.iter())
+        );
+
+        let mut corrupted = bytes.clone();
+        corrupted[HEADER_LENGTH] = 4;
+        assert_eq!(
+            VartimeEdwardsPrecomputation::from_bytes(&corrupted).err(),
+            Some(TableEncodingError::InvalidLength)
+        );
+
+        let mut corrupted = bytes;
+        corrupted[HEADER_LENGTH + 4..HEADER_LENGTH + 36]
+            .copy_from_slice(static_points[1].compress().as_bytes());
+        assert_eq!(
+            VartimeEdwardsPrecomputation::from_bytes(&corrupted).err(),
+            Some(TableEncodingError::PointMismatch)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_bincode_table_roundtrip() {
+        let table = &constants::ED25519_BASEPOINT_TABLE;
+        let encoded = bincode::serialize(table).unwrap();
+        let decoded: EdwardsBasepointTable = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded.to_bytes(), table.to_bytes());
+
+        let precomputation =
+            VartimeEdwardsPrecomputation::new([constants::ED25519_BASEPOINT_POINT].iter());
+        let encoded = bincode::serialize(&precomputation).unwrap();
+        let decoded: VartimeEdwardsPrecomputation = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded.to_bytes(), precomputation.to_bytes());
+
+        assert!(bincode::deserialize::<EdwardsBasepointTable>(&encoded).is_err());
+    }
+}
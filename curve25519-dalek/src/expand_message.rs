@@ -0,0 +1,309 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Message expansion for hashing to fields and curves, as specified in
+//! [RFC 9380, section 5.3][rfc9380].
+//!
+//! The `hash_to_field` functions in this crate, such as [`Scalar::hash_to_field`], are generic
+//! over an [`ExpandMessage`] implementation, which turns a message and a domain separation tag
+//! into an arbitrary number of uniformly random bytes. Two expanders are provided:
+//!
+//! * [`ExpandMsgXmd`], for a Merkle-Damgård hash function such as SHA-512;
+//! * [`ExpandMsgXof`], for an extendable-output function such as SHAKE256.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # fn main() {
+//! use curve25519_dalek::expand_message::ExpandMsgXmd;
+//! use curve25519_dalek::scalar::Scalar;
+//! use sha2::Sha512;
+//!
+//! let dst = b"MyProtocol-V1-CS01-with-ristretto255_XMD:SHA-512_R255MAP_RO_";
+//! let scalars = Scalar::hash_to_field::<ExpandMsgXmd<Sha512>>(&[b"Hello world"], dst, 2);
+//!
+//! assert_eq!(scalars.len(), 2);
+//! assert_ne!(scalars[0], scalars[1]);
+//! # }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+//!
+//! [rfc9380]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3
+//! [`Scalar::hash_to_field`]: crate::scalar::Scalar::hash_to_field
+
+use core::marker::PhantomData;
+
+use digest::{
+    crypto_common::BlockSizeUser, generic_array::GenericArray, Digest, ExtendableOutput, Update,
+    XofReader,
+};
+
+/// A method of expanding a message into uniformly random bytes, as described in
+/// [RFC 9380, section 5.3](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3).
+pub trait ExpandMessage {
+    /// Fill `out` with `expand_message(msg, dst, out.len())`, where `msg` is the concatenation
+    /// of `msgs`.
+    ///
+    /// Domain separation tags longer than 255 bytes are first hashed down as described in
+    /// section 5.3.3 of the RFC.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is empty, or if `out` is longer than the expander supports. This is at
+    /// most 65535 bytes.
+    fn expand_message(msgs: &[&[u8]], dst: &[u8], out: &mut [u8]);
+}
+
+/// `expand_message_xmd` from [RFC 9380, section 5.3.1][rfc9380], using the hash function `D`.
+///
+/// The output may be at most 255 times the output size of `D`, and at most 65535 bytes.
+///
+/// [rfc9380]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.1
+#[derive(Debug)]
+pub struct ExpandMsgXmd<D>(PhantomData<D>);
+
+impl<D> ExpandMessage for ExpandMsgXmd<D>
+where
+    D: Digest + BlockSizeUser,
+{
+    fn expand_message(msgs: &[&[u8]], dst: &[u8], out: &mut [u8]) {
+        let b_in_bytes = <D as Digest>::output_size();
+        let len_in_bytes = out.len();
+        let ell = (len_in_bytes + b_in_bytes - 1) / b_in_bytes;
+
+        assert!(!dst.is_empty(), "domain separation tag must be nonempty");
+        assert!(
+            ell <= 255,
+            "requested output is too long for this hash function"
+        );
+        assert!(len_in_bytes <= 65535, "requested output is too long");
+
+        // Hash oversized domain separation tags, per section 5.3.3
+        let dst_hash;
+        let dst = if dst.len() > 255 {
+            dst_hash = D::new()
+                .chain_update(b"H2C-OVERSIZE-DST-")
+                .chain_update(dst)
+                .finalize();
+            &dst_hash[..]
+        } else {
+            dst
+        };
+        let dst_len = [dst.len() as u8];
+
+        // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+        let mut h = D::new().chain_update(GenericArray::<u8, D::BlockSize>::default());
+        for msg in msgs {
+            Digest::update(&mut h, msg);
+        }
+        let b_0 = h
+            .chain_update((len_in_bytes as u16).to_be_bytes())
+            .chain_update([0u8])
+            .chain_update(dst)
+            .chain_update(dst_len)
+            .finalize();
+
+        // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+        let mut b_i = D::new()
+            .chain_update(&b_0)
+            .chain_update([1u8])
+            .chain_update(dst)
+            .chain_update(dst_len)
+            .finalize();
+
+        for (i, chunk) in out.chunks_mut(b_in_bytes).enumerate() {
+            if i > 0 {
+                // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+                let mut xored = b_0.clone();
+                for (x, b) in xored.iter_mut().zip(b_i.iter()) {
+                    *x ^= b;
+                }
+                b_i = D::new()
+                    .chain_update(&xored)
+                    .chain_update([(i + 1) as u8])
+                    .chain_update(dst)
+                    .chain_update(dst_len)
+                    .finalize();
+            }
+            chunk.copy_from_slice(&b_i[..chunk.len()]);
+        }
+    }
+}
+
+/// `expand_message_xof` from [RFC 9380, section 5.3.2][rfc9380], using the extendable-output
+/// function `X` at a target security level of `K` bits.
+///
+/// `K` only affects how oversized domain separation tags are hashed, and should be 128 for
+/// SHAKE128 and 256 for SHAKE256. The output may be at most 65535 bytes.
+///
+/// [rfc9380]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.2
+#[derive(Debug)]
+pub struct ExpandMsgXof<X, const K: usize>(PhantomData<X>);
+
+impl<X, const K: usize> ExpandMessage for ExpandMsgXof<X, K>
+where
+    X: Default + ExtendableOutput + Update,
+{
+    fn expand_message(msgs: &[&[u8]], dst: &[u8], out: &mut [u8]) {
+        let len_in_bytes = out.len();
+
+        assert!(!dst.is_empty(), "domain separation tag must be nonempty");
+        assert!(len_in_bytes <= 65535, "requested output is too long");
+
+        // Hash oversized domain separation tags to ceil(2 * k / 8) bytes, per section 5.3.3
+        let mut dst_hash = [0u8; 255];
+        let dst = if dst.len() > 255 {
+            let dst_hash = &mut dst_hash[..(2 * K + 7) / 8];
+            X::default()
+                .chain(b"H2C-OVERSIZE-DST-")
+                .chain(dst)
+                .finalize_xof()
+                .read(dst_hash);
+            &*dst_hash
+        } else {
+            dst
+        };
+
+        // uniform_bytes = H(msg || I2OSP(len_in_bytes, 2) || DST_prime, len_in_bytes)
+        let mut h = X::default();
+        for msg in msgs {
+            h.update(msg);
+        }
+        h.chain((len_in_bytes as u16).to_be_bytes())
+            .chain(dst)
+            .chain([dst.len() as u8])
+            .finalize_xof()
+            .read(out);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use sha2::Sha512;
+    use sha3::Shake256;
+
+    /// `expand_message_xmd(SHA-512)` test vectors from RFC 9380, appendix K.3, of the form
+    /// (msg, uniform_bytes).
+    const RFC9380_XMD_SHA512_KAT: &[(&[u8], &str)] = &[
+        (
+            b"",
+            "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba",
+        ),
+        (
+            b"abc",
+            "0da749f12fbe5483eb066a5f595055679b976e93abe9be6f0f6318bce7aca8dc",
+        ),
+        (
+            b"abcdef0123456789",
+            "087e45a86e2939ee8b91100af1583c4938e0f5fc6c9db4b107b83346bc967f58",
+        ),
+        (
+            b"abc",
+            "7f1dddd13c08b543f2e2037b14cefb255b44c83cc397c1786d975653e36a6b11\
+             bdd7732d8b38adb4a0edc26a0cef4bb45217135456e58fbca1703cd6032cb134\
+             7ee720b87972d63fbf232587043ed2901bce7f22610c0419751c065922b48843\
+             1851041310ad659e4b23520e1772ab29dcdeb2002222a363f0c2b1c972b3efe1",
+        ),
+    ];
+
+    /// `expand_message_xof(SHAKE256)` test vectors from RFC 9380, appendix K.5, of the form
+    /// (msg, uniform_bytes).
+    const RFC9380_XOF_SHAKE256_KAT: &[(&[u8], &str)] = &[
+        (
+            b"",
+            "2ffc05c48ed32b95d72e807f6eab9f7530dd1c2f013914c8fed38c5ccc15ad76",
+        ),
+        (
+            b"abc",
+            "b39e493867e2767216792abce1f2676c197c0692aed061560ead251821808e07",
+        ),
+        (
+            b"abcdef0123456789",
+            "245389cf44a13f0e70af8665fe5337ec2dcd138890bb7901c4ad9cfceb054b65",
+        ),
+        (
+            b"abc",
+            "a54303e6b172909783353ab05ef08dd435a558c3197db0c132134649708e0b9b\
+             4e34fb99b92a9e9e28fc1f1d8860d85897a8e021e6382f3eea10577f968ff6df\
+             6c45fe624ce65ca25932f679a42a404bc3681efe03fcd45ef73bb3a8f79ba784\
+             f80f55ea8a3c367408f30381299617f50c8cf8fbb21d0f1e1d70b0131a7b6fbe",
+        ),
+    ];
+
+    fn check_kat<E: ExpandMessage>(dst: &[u8], kat: &[(&[u8], &str)]) {
+        for (msg, uniform_bytes) in kat {
+            let mut out = [0u8; 128];
+            let out = &mut out[..uniform_bytes.len() / 2];
+            E::expand_message(&[*msg], dst, out);
+            assert_eq!(hex::encode(out), *uniform_bytes);
+        }
+    }
+
+    #[test]
+    fn expand_message_xmd_rfc9380_test_vectors() {
+        check_kat::<ExpandMsgXmd<Sha512>>(
+            b"QUUX-V01-CS02-with-expander-SHA512-256",
+            RFC9380_XMD_SHA512_KAT,
+        );
+    }
+
+    #[test]
+    fn expand_message_xof_rfc9380_test_vectors() {
+        check_kat::<ExpandMsgXof<Shake256, 256>>(
+            b"QUUX-V01-CS02-with-expander-SHAKE256",
+            RFC9380_XOF_SHAKE256_KAT,
+        );
+    }
+
+    /// Splitting the message into several slices must not change the output.
+    #[test]
+    fn expand_message_split_msgs() {
+        fn check<E: ExpandMessage>() {
+            let mut whole = [0u8; 96];
+            let mut split = [0u8; 96];
+            E::expand_message(&[b"abcdef0123456789"], b"DST", &mut whole);
+            E::expand_message(&[b"abc", b"", b"def0123456789"], b"DST", &mut split);
+            assert_eq!(whole, split);
+        }
+        check::<ExpandMsgXmd<Sha512>>();
+        check::<ExpandMsgXof<Shake256, 256>>();
+    }
+
+    /// Oversized domain separation tags are replaced by their hash, per section 5.3.3.
+    #[test]
+    fn expand_message_long_dst() {
+        let long_dst = [0x31u8; 300];
+        let mut out = [0u8; 64];
+        let mut expected = [0u8; 64];
+
+        ExpandMsgXmd::<Sha512>::expand_message(&[b"abc"], &long_dst, &mut out);
+        let dst_hash = Sha512::new()
+            .chain_update(b"H2C-OVERSIZE-DST-")
+            .chain_update(long_dst)
+            .finalize();
+        ExpandMsgXmd::<Sha512>::expand_message(&[b"abc"], &dst_hash, &mut expected);
+        assert_eq!(out, expected);
+
+        ExpandMsgXof::<Shake256, 256>::expand_message(&[b"abc"], &long_dst, &mut out);
+        let mut dst_hash = [0u8; 64];
+        Shake256::default()
+            .chain(b"H2C-OVERSIZE-DST-")
+            .chain(long_dst)
+            .finalize_xof()
+            .read(&mut dst_hash);
+        ExpandMsgXof::<Shake256, 256>::expand_message(&[b"abc"], &dst_hash, &mut expected);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn expand_message_empty_dst() {
+        ExpandMsgXmd::<Sha512>::expand_message(&[b"abc"], b"", &mut [0u8; 32]);
+    }
+}
@@ -11,10 +11,10 @@
 
 //! Field arithmetic modulo \\(p = 2\^{255} - 19\\).
 //!
-//! The `curve25519_dalek::field` module provides a type alias
-//! `curve25519_dalek::field::FieldElement` to a field element type
-//! defined in the `backend` module; either `FieldElement51` or
-//! `FieldElement2625`.
+//! The `curve25519_dalek::field` module provides the public
+//! [`FieldElement25519`] type, which wraps the internal type alias
+//! `FieldElement` to a field element type defined in the `backend`
+//! module; either `FieldElement51` or `FieldElement2625`.
 //!
 //! Field operations defined in terms of machine
 //! operations, such as field multiplication or squaring, are defined in
@@ -25,16 +25,42 @@
 
 #![allow(unused_qualifications)]
 
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::iter::{Product, Sum};
+use core::ops::Neg;
+use core::ops::{Add, AddAssign};
+use core::ops::{Mul, MulAssign};
+use core::ops::{Sub, SubAssign};
+
 use cfg_if::cfg_if;
 
 use subtle::Choice;
 use subtle::ConditionallyNegatable;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
+use subtle::CtOption;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::de::Visitor;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 use crate::backend;
 use crate::constants;
 
+#[cfg(feature = "digest")]
+use digest::{crypto_common::BlockSizeUser, Digest};
+
+#[cfg(feature = "digest")]
+use crate::expand_message::{ExpandMessage, ExpandMsgXmd};
+
 cfg_if! {
     if #[cfg(curve25519_dalek_backend = "fiat")] {
         /// A `FieldElement` represents an element of the field
@@ -204,6 +230,7 @@ impl FieldElement {
     #[rustfmt::skip] // keep alignment of explanatory comments
     #[allow(clippy::let_and_return)]
     pub(crate) fn invert(&self) -> FieldElement {
+        count_op!(FIELD_INVERT);
         // The bits of p-2 = 2^255 -19 -2 are 11010111111...11.
         //
         //                                 nonzero bits of exponent
@@ -214,10 +241,26 @@ impl FieldElement {
         t21
     }
 
+    /// Check that `hint` is the inverse of this field element, as computed by
+    /// [`FieldElement::invert`], without computing the inverse.
+    ///
+    /// Since `invert` returns zero on input zero, the hint for zero is zero.
+    ///
+    /// # Return
+    ///
+    /// - `Some(hint)` if `hint` is the inverse of `self`;
+    /// - `None` otherwise.
+    pub(crate) fn invert_with_hint(&self, hint: &FieldElement) -> CtOption<FieldElement> {
+        let is_inverse = (self * hint).ct_eq(&FieldElement::ONE);
+        let both_zero = self.is_zero() & hint.is_zero();
+
+        CtOption::new(*hint, is_inverse | both_zero)
+    }
+
     /// Raise this field element to the power (p-5)/8 = 2^252 -3.
     #[rustfmt::skip] // keep alignment of explanatory comments
     #[allow(clippy::let_and_return)]
-    fn pow_p58(&self) -> FieldElement {
+    pub(crate) fn pow_p58(&self) -> FieldElement {
         // The bits of (p-5)/8 are 101111.....11.
         //
         //                                 nonzero bits of exponent
@@ -303,6 +346,485 @@ impl FieldElement {
     pub(crate) fn invsqrt(&self) -> (Choice, FieldElement) {
         FieldElement::sqrt_ratio_i(&FieldElement::ONE, self)
     }
+
+    /// Reduce a 512-bit little-endian integer modulo \\(p\\).
+    pub(crate) fn from_bytes_wide(bytes: &[u8; 64]) -> FieldElement {
+        // Write the input as lo + 2^255 lo_hi + 2^256 (hi + 2^255 hi_hi), where lo and hi are
+        // 255-bit integers. Since 2^255 = 19 and 2^256 = 38 (mod p), this is
+        //     lo + 19 lo_hi + 38 hi + 722 hi_hi  (mod p).
+        let mut lo = [0u8; 32];
+        let mut hi = [0u8; 32];
+        lo.copy_from_slice(&bytes[..32]);
+        hi.copy_from_slice(&bytes[32..]);
+
+        // FieldElement::from_bytes ignores the high bit, so we handle it separately.
+        let top_bits = 19 * u16::from(lo[31] >> 7) + 722 * u16::from(hi[31] >> 7);
+        let mut top_bits_bytes = [0u8; 32];
+        top_bits_bytes[..2].copy_from_slice(&top_bits.to_le_bytes());
+
+        let mut thirty_eight = [0u8; 32];
+        thirty_eight[0] = 38;
+
+        let lo = FieldElement::from_bytes(&lo);
+        let hi = FieldElement::from_bytes(&hi);
+        &(&lo + &FieldElement::from_bytes(&top_bits_bytes))
+            + &(&hi * &FieldElement::from_bytes(&thirty_eight))
+    }
+
+    /// Hash `msg` to `N` field elements with domain separation tag `dst`, as specified by the
+    /// `hash_to_field` function of [RFC 9380][rfc9380], using `expand_message_xmd` with the
+    /// hash function `D`.
+    ///
+    /// Each field element is derived from \\(L = 48\\) bytes of the expanded message, so that
+    /// the bias of the result is negligible.
+    ///
+    /// [rfc9380]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2
+    #[cfg(feature = "digest")]
+    pub(crate) fn hash_to_field<D, const N: usize>(msg: &[u8], dst: &[u8]) -> [FieldElement; N]
+    where
+        D: Digest + BlockSizeUser,
+    {
+        // The hash-to-curve suites only ever need one or two field elements.
+        debug_assert!(N == 1 || N == 2);
+
+        let mut uniform_bytes = [0u8; 96];
+        let uniform_bytes = &mut uniform_bytes[..48 * N];
+        ExpandMsgXmd::<D>::expand_message(&[msg], dst, uniform_bytes);
+
+        let mut result = [FieldElement::ZERO; N];
+        for (fe, chunk) in result.iter_mut().zip(uniform_bytes.chunks(48)) {
+            // The chunk is a big-endian integer; reverse it into a little-endian buffer.
+            let mut wide = [0u8; 64];
+            wide[..48].copy_from_slice(chunk);
+            wide[..48].reverse();
+            *fe = FieldElement::from_bytes_wide(&wide);
+        }
+        result
+    }
+}
+
+/// An element of the field \\( \mathbb Z / (2\^{255} - 19)\\).
+///
+/// This is the public face of the field arithmetic used internally by this crate, for building
+/// custom encodings, map-to-curve variants, and the like. It is backed by whichever serial
+/// backend is selected at compile time (`FieldElement51` on 64-bit targets, `FieldElement2625`
+/// on 32-bit targets, or their fiat-crypto counterparts), and all of its operations are
+/// constant-time with respect to the values of the field elements.
+///
+/// The canonical encoding of a field element is the 32-byte little-endian encoding of its
+/// representative in \\( [0, p) \\), which always has the high bit clear.
+///
+/// # Example
+///
+/// ```
+/// use curve25519_dalek::field::FieldElement25519;
+///
+/// let two = FieldElement25519::from(2u64);
+/// let four = two.square();
+///
+/// assert_eq!(four.sqrt().unwrap(), two);
+/// assert_eq!(two.legendre_symbol(), -1);
+/// assert_eq!(&two * &two.invert(), FieldElement25519::ONE);
+/// assert_eq!(
+///     FieldElement25519::from_canonical_bytes(four.to_bytes()).unwrap(),
+///     four
+/// );
+/// ```
+#[derive(Copy, Clone)]
+pub struct FieldElement25519(pub(crate) FieldElement);
+
+impl FieldElement25519 {
+    /// The field element zero.
+    pub const ZERO: Self = FieldElement25519(FieldElement::ZERO);
+
+    /// The field element one.
+    pub const ONE: Self = FieldElement25519(FieldElement::ONE);
+
+    /// The field element \\( -1 \\).
+    pub const MINUS_ONE: Self = FieldElement25519(FieldElement::MINUS_ONE);
+
+    /// The nonnegative square root of \\( -1 \\).
+    pub const SQRT_M1: Self = FieldElement25519(constants::SQRT_M1);
+
+    /// Construct a field element by reducing a 256-bit little-endian integer modulo \\(p\\).
+    pub fn from_bytes_mod_order(bytes: [u8; 32]) -> Self {
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(&bytes);
+        FieldElement25519(FieldElement::from_bytes_wide(&wide))
+    }
+
+    /// Construct a field element by reducing a 512-bit little-endian integer modulo \\(p\\).
+    ///
+    /// If the input bytes are uniformly distributed, the output is statistically close to
+    /// uniform.
+    pub fn from_bytes_mod_order_wide(bytes: &[u8; 64]) -> Self {
+        FieldElement25519(FieldElement::from_bytes_wide(bytes))
+    }
+
+    /// Attempt to construct a field element from its canonical encoding.
+    ///
+    /// # Return
+    ///
+    /// - `Some(x)` if `bytes` is the canonical encoding of `x`;
+    /// - `None` if `bytes` encodes an integer \\( \geq p \\), including any encoding with the
+    ///   high bit set.
+    pub fn from_canonical_bytes(bytes: [u8; 32]) -> CtOption<Self> {
+        let fe = FieldElement::from_bytes(&bytes);
+        let is_canonical = fe.as_bytes().ct_eq(&bytes);
+        CtOption::new(FieldElement25519(fe), is_canonical)
+    }
+
+    /// Encode this field element canonically, as 32 little-endian bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.as_bytes()
+    }
+
+    /// Determine if this field element is zero.
+    pub fn is_zero(&self) -> Choice {
+        self.0.is_zero()
+    }
+
+    /// Determine if this field element is negative, in the sense used in the ed25519 paper:
+    /// \\(x\\) is negative if the low bit of its canonical encoding is set.
+    pub fn is_negative(&self) -> Choice {
+        self.0.is_negative()
+    }
+
+    /// Compute the square of this field element.
+    pub fn square(&self) -> Self {
+        FieldElement25519(self.0.square())
+    }
+
+    /// Square this field element `k` times in succession, i.e. raise it to the power
+    /// \\( 2^k \\).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero.
+    pub fn pow2k(&self, k: u32) -> Self {
+        assert!(k > 0);
+        FieldElement25519(self.0.pow2k(k))
+    }
+
+    /// Raise this field element to the power \\( (p-5)/8 = 2^{252} - 3 \\).
+    ///
+    /// This is the exponentiation at the heart of square root computations in this field.
+    pub fn pow_p58(&self) -> Self {
+        FieldElement25519(self.0.pow_p58())
+    }
+
+    /// Compute the multiplicative inverse of this field element, as \\( x^{p-2} \\).
+    ///
+    /// Zero has no inverse, and this function returns zero on input zero.
+    pub fn invert(&self) -> Self {
+        FieldElement25519(self.0.invert())
+    }
+
+    /// Check a claimed inverse `hint` of this field element, and return it if it is correct.
+    ///
+    /// This costs a single multiplication, so in environments where an exponentiation is
+    /// expensive, such as zkVMs, the inverse can be computed natively with
+    /// [`FieldElement25519::invert`] and passed in as a hint. As with `invert`, the inverse
+    /// of zero is taken to be zero.
+    ///
+    /// # Return
+    ///
+    /// - `Some(hint)` if `hint` equals `self.invert()`;
+    /// - `None` otherwise.
+    pub fn invert_with_hint(&self, hint: &Self) -> CtOption<Self> {
+        let checked = self.0.invert_with_hint(&hint.0);
+        CtOption::new(*hint, checked.is_some())
+    }
+
+    /// Replace each element of `inputs` with its inverse, using Montgomery's trick to compute
+    /// all of them with a single field inversion.
+    ///
+    /// Zero elements are left unchanged, without affecting the other results.
+    #[cfg(feature = "alloc")]
+    pub fn batch_invert(inputs: &mut [FieldElement25519]) {
+        let mut elements: Vec<FieldElement> = inputs.iter().map(|x| x.0).collect();
+        FieldElement::batch_invert(&mut elements);
+        for (input, inverse) in inputs.iter_mut().zip(elements) {
+            input.0 = inverse;
+        }
+    }
+
+    /// Compute the Legendre symbol of this field element, as \\( x^{(p-1)/2} \\).
+    ///
+    /// # Return
+    ///
+    /// - `1` if `self` is a nonzero square;
+    /// - `0` if `self` is zero;
+    /// - `-1` if `self` is a nonsquare.
+    pub fn legendre_symbol(&self) -> i8 {
+        // (p-1)/2 = 4 (p-5)/8 + 2
+        let chi = &self.0.pow_p58().pow2k(2) * &self.0.square();
+
+        let mut symbol = 0i8;
+        symbol.conditional_assign(&1, chi.ct_eq(&FieldElement::ONE));
+        symbol.conditional_assign(&-1, chi.ct_eq(&FieldElement::MINUS_ONE));
+        symbol
+    }
+
+    /// Compute the nonnegative square root of this field element, if it exists.
+    ///
+    /// # Return
+    ///
+    /// - `Some(r)` if `self` is a square, where `r` is nonnegative and \\( r^2 = x \\);
+    /// - `None` if `self` is a nonsquare.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        let (is_square, r) = FieldElement::sqrt_ratio_i(&self.0, &FieldElement::ONE);
+        CtOption::new(FieldElement25519(r), is_square)
+    }
+
+    /// Given field elements `u` and `v`, compute either \\( \sqrt{u/v} \\) or
+    /// \\( \sqrt{i u / v} \\), where \\( i = \sqrt{-1} \\).
+    ///
+    /// This function always returns the nonnegative square root.
+    ///
+    /// # Return
+    ///
+    /// - `(Choice(1), +sqrt(u/v))  ` if `v` is nonzero and `u/v` is square;
+    /// - `(Choice(1), zero)        ` if `u` is zero;
+    /// - `(Choice(0), zero)        ` if `v` is zero and `u` is nonzero;
+    /// - `(Choice(0), +sqrt(i*u/v))` if `u/v` is nonsquare (so `i*u/v` is square).
+    pub fn sqrt_ratio_i(u: &Self, v: &Self) -> (Choice, Self) {
+        let (was_square, r) = FieldElement::sqrt_ratio_i(&u.0, &v.0);
+        (was_square, FieldElement25519(r))
+    }
+
+    /// Compute \\( 1/\sqrt{x} \\) or \\( 1/\sqrt{i x} \\) for this element \\(x\\).
+    ///
+    /// This function always returns the nonnegative square root.
+    ///
+    /// # Return
+    ///
+    /// - `(Choice(1), +sqrt(1/self))  ` if `self` is a nonzero square;
+    /// - `(Choice(0), zero)           ` if `self` is zero;
+    /// - `(Choice(0), +sqrt(i/self))  ` if `self` is a nonzero nonsquare.
+    pub fn invsqrt(&self) -> (Choice, Self) {
+        let (was_square, r) = self.0.invsqrt();
+        (was_square, FieldElement25519(r))
+    }
+}
+
+impl Debug for FieldElement25519 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "FieldElement25519{{\n\tbytes: {:?},\n}}",
+            &self.to_bytes()
+        )
+    }
+}
+
+impl Default for FieldElement25519 {
+    fn default() -> FieldElement25519 {
+        FieldElement25519::ZERO
+    }
+}
+
+impl From<u64> for FieldElement25519 {
+    fn from(x: u64) -> FieldElement25519 {
+        let mut bytes = [0u8; 32];
+        bytes[..8].copy_from_slice(&x.to_le_bytes());
+        FieldElement25519(FieldElement::from_bytes(&bytes))
+    }
+}
+
+impl Eq for FieldElement25519 {}
+
+impl PartialEq for FieldElement25519 {
+    fn eq(&self, other: &FieldElement25519) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl ConstantTimeEq for FieldElement25519 {
+    fn ct_eq(&self, other: &FieldElement25519) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for FieldElement25519 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        FieldElement25519(FieldElement::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl Add<&FieldElement25519> for &FieldElement25519 {
+    type Output = FieldElement25519;
+    fn add(self, rhs: &FieldElement25519) -> FieldElement25519 {
+        FieldElement25519(&self.0 + &rhs.0)
+    }
+}
+
+define_add_variants!(
+    LHS = FieldElement25519,
+    RHS = FieldElement25519,
+    Output = FieldElement25519
+);
+
+impl AddAssign<&FieldElement25519> for FieldElement25519 {
+    fn add_assign(&mut self, rhs: &FieldElement25519) {
+        self.0 += &rhs.0;
+    }
+}
+
+define_add_assign_variants!(LHS = FieldElement25519, RHS = FieldElement25519);
+
+impl Sub<&FieldElement25519> for &FieldElement25519 {
+    type Output = FieldElement25519;
+    fn sub(self, rhs: &FieldElement25519) -> FieldElement25519 {
+        FieldElement25519(&self.0 - &rhs.0)
+    }
+}
+
+define_sub_variants!(
+    LHS = FieldElement25519,
+    RHS = FieldElement25519,
+    Output = FieldElement25519
+);
+
+impl SubAssign<&FieldElement25519> for FieldElement25519 {
+    fn sub_assign(&mut self, rhs: &FieldElement25519) {
+        self.0 -= &rhs.0;
+    }
+}
+
+define_sub_assign_variants!(LHS = FieldElement25519, RHS = FieldElement25519);
+
+impl Mul<&FieldElement25519> for &FieldElement25519 {
+    type Output = FieldElement25519;
+    fn mul(self, rhs: &FieldElement25519) -> FieldElement25519 {
+        FieldElement25519(&self.0 * &rhs.0)
+    }
+}
+
+define_mul_variants!(
+    LHS = FieldElement25519,
+    RHS = FieldElement25519,
+    Output = FieldElement25519
+);
+
+impl MulAssign<&FieldElement25519> for FieldElement25519 {
+    fn mul_assign(&mut self, rhs: &FieldElement25519) {
+        self.0 *= &rhs.0;
+    }
+}
+
+define_mul_assign_variants!(LHS = FieldElement25519, RHS = FieldElement25519);
+
+impl Neg for &FieldElement25519 {
+    type Output = FieldElement25519;
+    fn neg(self) -> FieldElement25519 {
+        FieldElement25519(-&self.0)
+    }
+}
+
+impl Neg for FieldElement25519 {
+    type Output = FieldElement25519;
+    fn neg(self) -> FieldElement25519 {
+        -&self
+    }
+}
+
+impl<T> Sum<T> for FieldElement25519
+where
+    T: Borrow<FieldElement25519>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(FieldElement25519::ZERO, |acc, item| acc + item.borrow())
+    }
+}
+
+impl<T> Product<T> for FieldElement25519
+where
+    T: Borrow<FieldElement25519>,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(FieldElement25519::ONE, |acc, item| acc * item.borrow())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for FieldElement25519 {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for FieldElement25519 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeTuple;
+        let mut tup = serializer.serialize_tuple(32)?;
+        for byte in self.to_bytes().iter() {
+            tup.serialize_element(byte)?;
+        }
+        tup.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for FieldElement25519 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FieldElementVisitor;
+
+        impl<'de> Visitor<'de> for FieldElementVisitor {
+            type Value = FieldElement25519;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str(
+                    "a sequence of 32 bytes whose little-endian interpretation is less than \
+                    2^255 - 19",
+                )
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<FieldElement25519, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut bytes = [0u8; 32];
+                #[allow(clippy::needless_range_loop)]
+                for i in 0..32 {
+                    bytes[i] = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(i, &"expected 32 bytes"))?;
+                }
+                Option::from(FieldElement25519::from_canonical_bytes(bytes)).ok_or_else(|| {
+                    serde::de::Error::custom("field element was not canonically encoded")
+                })
+            }
+        }
+
+        deserializer.deserialize_tuple(32, FieldElementVisitor)
+    }
+}
+
+/// Decode a field element from the big-endian hex encoding used in RFC 9380.
+#[cfg(test)]
+pub(crate) fn fe_from_rfc_hex(hex_str: &str) -> FieldElement {
+    let mut bytes: [u8; 32] = hex::decode(hex_str)
+        .expect("invalid hex")
+        .try_into()
+        .expect("field element should be 32 bytes");
+    bytes.reverse();
+    FieldElement::from_bytes(&bytes)
 }
 
 #[cfg(test)]
@@ -492,4 +1014,266 @@ mod test {
     fn batch_invert_empty() {
         FieldElement::batch_invert(&mut []);
     }
+
+    /// `hash_to_field` test vectors for `edwards25519_XMD:SHA-512_ELL2_RO_` from RFC 9380,
+    /// appendix J.5.1, of the form (msg, u[0], u[1]).
+    #[cfg(feature = "digest")]
+    const RFC9380_HASH_TO_FIELD_RO_KAT: &[(&[u8], &str, &str)] = &[
+        (
+            b"",
+            "03fef4813c8cb5f98c6eef88fae174e6e7d5380de2b007799ac7ee712d203f3a",
+            "780bdddd137290c8f589dc687795aafae35f6b674668d92bf92ae793e6a60c75",
+        ),
+        (
+            b"abc",
+            "5081955c4141e4e7d02ec0e36becffaa1934df4d7a270f70679c78f9bd57c227",
+            "005bdc17a9b378b6272573a31b04361f21c371b256252ae5463119aa0b925b76",
+        ),
+        (
+            b"abcdef0123456789",
+            "285ebaa3be701b79871bcb6e225ecc9b0b32dff2d60424b4c50642636a78d5b3",
+            "2e253e6a0ef658fedb8e4bd6a62d1544fd6547922acb3598ec6b369760b81b31",
+        ),
+        (
+            b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+            "4fedd25431c41f2a606952e2945ef5e3ac905a42cf64b8b4d4a83c533bf321af",
+            "02f20716a5801b843987097a8276b6d869295b2e11253751ca72c109d37485a9",
+        ),
+        (
+            b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "6e34e04a5106e9bd59f64aba49601bf09d23b27f7b594e56d5de06df4a4ea33b",
+            "1c1c2cb59fc053f44b86c5d5eb8c1954b64976d0302d3729ff66e84068f5fd96",
+        ),
+    ];
+
+    /// `hash_to_field` test vectors for `edwards25519_XMD:SHA-512_ELL2_NU_` from RFC 9380,
+    /// appendix J.5.2, of the form (msg, u[0]).
+    #[cfg(feature = "digest")]
+    const RFC9380_HASH_TO_FIELD_NU_KAT: &[(&[u8], &str)] = &[
+        (
+            b"",
+            "7f3e7fb9428103ad7f52db32f9df32505d7b427d894c5093f7a0f0374a30641d",
+        ),
+        (
+            b"abc",
+            "09cfa30ad79bd59456594a0f5d3a76f6b71c6787b04de98be5cd201a556e253b",
+        ),
+        (
+            b"abcdef0123456789",
+            "475ccff99225ef90d78cc9338e9f6a6bb7b17607c0c4428937de75d33edba941",
+        ),
+        (
+            b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+            "049a1c8bd51bcb2aec339f387d1ff51428b88d0763a91bcdf6929814ac95d03d",
+        ),
+        (
+            b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "3cb0178a8137cefa5b79a3a57c858d7eeeaa787b2781be4a362a2f0750d24fa0",
+        ),
+    ];
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn hash_to_field_rfc9380_test_vectors() {
+        let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+        for (msg, u_0, u_1) in RFC9380_HASH_TO_FIELD_RO_KAT.iter() {
+            let u = FieldElement::hash_to_field::<sha2::Sha512, 2>(msg, dst);
+            assert_eq!(u[0], fe_from_rfc_hex(u_0));
+            assert_eq!(u[1], fe_from_rfc_hex(u_1));
+        }
+
+        let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_";
+        for (msg, u_0) in RFC9380_HASH_TO_FIELD_NU_KAT.iter() {
+            let u = FieldElement::hash_to_field::<sha2::Sha512, 1>(msg, dst);
+            assert_eq!(u[0], fe_from_rfc_hex(u_0));
+        }
+    }
+
+    #[test]
+    fn from_bytes_wide_reduces_mod_p() {
+        // 2^512 - 1 = 2^256 * (2^256 - 1) + (2^256 - 1) = 39 * (2^256 - 1) = 39 * 37 (mod p)
+        let all_ones = FieldElement::from_bytes_wide(&[0xff; 64]);
+        let mut expected = [0u8; 32];
+        expected[..2].copy_from_slice(&(39u16 * 37).to_le_bytes());
+        assert_eq!(all_ones, FieldElement::from_bytes(&expected));
+
+        // The low half alone is reduced like from_bytes, but keeping the high bit.
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(&A_BYTES);
+        assert_eq!(
+            FieldElement::from_bytes_wide(&wide),
+            FieldElement::from_bytes(&A_BYTES)
+        );
+    }
+
+    fn random_field_element() -> FieldElement25519 {
+        use rand_core::{OsRng, RngCore};
+
+        let mut bytes = [0u8; 64];
+        OsRng.fill_bytes(&mut bytes);
+        FieldElement25519::from_bytes_mod_order_wide(&bytes)
+    }
+
+    #[test]
+    fn public_canonical_encoding() {
+        // p = 2^255 - 19 and p - 1, little-endian
+        let mut p_bytes = [0xff; 32];
+        p_bytes[0] = 0xed;
+        p_bytes[31] = 0x7f;
+        let mut p_minus_one_bytes = p_bytes;
+        p_minus_one_bytes[0] = 0xec;
+
+        assert!(bool::from(
+            FieldElement25519::from_canonical_bytes(p_bytes).is_none()
+        ));
+        assert!(bool::from(
+            FieldElement25519::from_canonical_bytes([0xff; 32]).is_none()
+        ));
+        assert_eq!(
+            FieldElement25519::from_canonical_bytes(p_minus_one_bytes).unwrap(),
+            FieldElement25519::MINUS_ONE
+        );
+        assert_eq!(FieldElement25519::MINUS_ONE.to_bytes(), p_minus_one_bytes);
+
+        // Non-canonical encodings are reduced, including the high bit.
+        assert_eq!(
+            FieldElement25519::from_bytes_mod_order(p_bytes),
+            FieldElement25519::ZERO
+        );
+        assert_eq!(
+            FieldElement25519::from_bytes_mod_order([0xff; 32]),
+            FieldElement25519::from(37u64)
+        );
+
+        for _ in 0..100 {
+            let x = random_field_element();
+            assert_eq!(
+                FieldElement25519::from_canonical_bytes(x.to_bytes()).unwrap(),
+                x
+            );
+            assert_eq!(FieldElement25519::from_bytes_mod_order(x.to_bytes()), x);
+        }
+    }
+
+    #[test]
+    fn public_arithmetic() {
+        let x = random_field_element();
+        let y = random_field_element();
+
+        assert_eq!((x + y) - y, x);
+        assert_eq!(x - x, FieldElement25519::ZERO);
+        assert_eq!(-x + x, FieldElement25519::ZERO);
+        assert_eq!(x * FieldElement25519::MINUS_ONE, -x);
+        assert_eq!(x * y * y.invert(), x);
+        assert_eq!(x.square(), x * x);
+        assert_eq!(x.pow2k(2), x * x * x * x);
+        assert_eq!(
+            FieldElement25519::SQRT_M1.square(),
+            FieldElement25519::MINUS_ONE
+        );
+        assert_eq!(FieldElement25519::ZERO.invert(), FieldElement25519::ZERO);
+
+        let mut z = x;
+        z += y;
+        z *= y;
+        z -= x * y;
+        assert_eq!(z, y.square());
+
+        let xs = [x, y, x];
+        assert_eq!(xs.iter().sum::<FieldElement25519>(), x + x + y);
+        assert_eq!(xs.iter().product::<FieldElement25519>(), x * x * y);
+    }
+
+    #[test]
+    fn public_sqrt_and_legendre_symbol() {
+        assert_eq!(FieldElement25519::ZERO.legendre_symbol(), 0);
+        assert_eq!(
+            FieldElement25519::ZERO.sqrt().unwrap(),
+            FieldElement25519::ZERO
+        );
+
+        // 2 is a nonsquare, since p = 5 (mod 8)
+        let two = FieldElement25519::from(2u64);
+        for _ in 0..100 {
+            let x = random_field_element();
+            let x_sq = x.square();
+
+            assert_eq!(x_sq.legendre_symbol(), 1);
+            let r = x_sq.sqrt().unwrap();
+            assert!(bool::from(!r.is_negative()));
+            assert!(r == x || r == -x);
+
+            let (was_square, s) = x_sq.invsqrt();
+            assert!(bool::from(was_square));
+            assert!(bool::from(!s.is_negative()));
+            assert_eq!(s.square() * x_sq, FieldElement25519::ONE);
+
+            let nonsquare = two * x_sq;
+            assert_eq!(nonsquare.legendre_symbol(), -1);
+            assert!(bool::from(nonsquare.sqrt().is_none()));
+            let (was_square, s) = FieldElement25519::sqrt_ratio_i(&nonsquare, &x_sq);
+            assert!(bool::from(!was_square));
+            assert_eq!(s.square(), FieldElement25519::SQRT_M1 * two);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn public_batch_invert() {
+        let x = random_field_element();
+        let y = random_field_element();
+        let mut xs = [x, FieldElement25519::ZERO, y];
+        FieldElement25519::batch_invert(&mut xs);
+        assert_eq!(xs, [x.invert(), FieldElement25519::ZERO, y.invert()]);
+
+        FieldElement25519::batch_invert(&mut []);
+    }
+
+    #[test]
+    fn public_invert_with_hint() {
+        let x = random_field_element();
+        let x_inv = x.invert();
+        assert_eq!(x.invert_with_hint(&x_inv).into_option(), Some(x_inv));
+        assert!(bool::from(
+            x.invert_with_hint(&(x_inv + FieldElement25519::ONE))
+                .is_none()
+        ));
+        assert!(bool::from(
+            x.invert_with_hint(&FieldElement25519::ZERO).is_none()
+        ));
+
+        let zero = FieldElement25519::ZERO;
+        assert_eq!(
+            zero.invert_with_hint(&zero).into_option(),
+            Some(zero.invert())
+        );
+        assert!(bool::from(
+            zero.invert_with_hint(&FieldElement25519::ONE).is_none()
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_bincode_field_element_roundtrip() {
+        let x = random_field_element();
+        let encoded = bincode::serialize(&x).unwrap();
+        assert_eq!(encoded, x.to_bytes());
+        let decoded: FieldElement25519 = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded, x);
+
+        // Non-canonical encodings are rejected.
+        assert!(bincode::deserialize::<FieldElement25519>(&[0xff; 32]).is_err());
+    }
 }
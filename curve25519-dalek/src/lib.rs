@@ -83,27 +83,43 @@ pub mod ristretto;
 // Useful constants, like the Ed25519 basepoint
 pub mod constants;
 
+// Finite field arithmetic mod p = 2^255 - 19
+pub mod field;
+
 // External (and internal) traits.
 pub mod traits;
 
+// Delegation of Edwards point operations to precompiles or syscalls
+pub mod accelerator;
+
+// Message expansion for hashing to fields and curves
+#[cfg(feature = "digest")]
+pub mod expand_message;
+
 //------------------------------------------------------------------------
 // curve25519-dalek internal modules
 //------------------------------------------------------------------------
 
-// Finite field arithmetic mod p = 2^255 - 19
-pub(crate) mod field;
-
 // Arithmetic backends (using u32, u64, etc) live here
 #[cfg(docsrs)]
 pub mod backend;
 #[cfg(not(docsrs))]
 pub(crate) mod backend;
 
+// Operation counters for profiling
+#[cfg(feature = "instrumentation")]
+pub use crate::backend::instrumentation;
+
 // Generic code for window lookups
 pub(crate) mod window;
 
+// Lizard encoding of byte strings into the Ristretto group
+#[cfg(feature = "lizard")]
+mod lizard;
+
 pub use crate::{
-    edwards::EdwardsPoint, montgomery::MontgomeryPoint, ristretto::RistrettoPoint, scalar::Scalar,
+    edwards::EdwardsPoint, field::FieldElement25519, montgomery::MontgomeryPoint,
+    ristretto::RistrettoPoint, scalar::Scalar,
 };
 
 // Build time diagnostics for validation
@@ -0,0 +1,388 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Lizard: an injective encoding of 16-byte strings into the Ristretto group.
+//!
+//! [`RistrettoPoint::lizard_encode`] hashes the payload, splices the payload into the middle of
+//! the hash, and maps the result to the group with the Ristretto-flavoured Elligator2 map.
+//! [`RistrettoPoint::lizard_decode`] inverts that map, which has at most eight nonnegative
+//! preimages for any point, and keeps the preimage whose hash matches its payload.
+//!
+//! This is the construction used by Signal's zkgroup and by the `ristretto.sage` reference
+//! implementation, and is useful for embedding short messages in ElGamal ciphertexts.
+
+#![allow(non_snake_case)]
+
+use digest::{generic_array::typenum::U32, Digest};
+
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use crate::constants;
+use crate::field::FieldElement;
+use crate::ristretto::RistrettoPoint;
+
+impl RistrettoPoint {
+    /// Encode 16 bytes of data to a `RistrettoPoint`, using the Lizard method.
+    ///
+    /// The hash function `D` is used to make the encoding uniquely decodable, and must be the
+    /// same one passed to [`RistrettoPoint::lizard_decode`]. Use SHA-256 if otherwise unsure;
+    /// this is what Signal and the reference implementation use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use sha2::Sha256;
+    ///
+    /// # fn main() {
+    /// let data = *b"sixteen bytes!!!";
+    /// let P = RistrettoPoint::lizard_encode::<Sha256>(&data);
+    ///
+    /// assert_eq!(P.lizard_decode::<Sha256>(), Some(data));
+    /// # }
+    /// ```
+    pub fn lizard_encode<D>(data: &[u8; 16]) -> RistrettoPoint
+    where
+        D: Digest<OutputSize = U32>,
+    {
+        let mut fe_bytes = [0u8; 32];
+        fe_bytes.copy_from_slice(&D::digest(data));
+        fe_bytes[8..24].copy_from_slice(data);
+        // Clear the low bit, so that the field element is nonnegative, and the two high bits,
+        // so that it is canonical
+        fe_bytes[0] &= 0b1111_1110;
+        fe_bytes[31] &= 0b0011_1111;
+
+        RistrettoPoint::elligator_ristretto_flavor(&FieldElement::from_bytes(&fe_bytes))
+    }
+
+    /// Decode 16 bytes of data from a `RistrettoPoint` produced by
+    /// [`RistrettoPoint::lizard_encode`], using the same hash function `D`.
+    ///
+    /// # Return
+    ///
+    /// * `Some(data)` if `self` is the Lizard encoding of `data`;
+    /// * `None` if `self` was not produced by `lizard_encode`, which is overwhelmingly
+    ///   likely for a random point.
+    pub fn lizard_decode<D>(&self) -> Option<[u8; 16]>
+    where
+        D: Digest<OutputSize = U32>,
+    {
+        let mut result = [0u8; 16];
+        let mut n_found = 0u8;
+
+        // lizard_encode only produces nonnegative field elements, so it suffices to check the
+        // nonnegative preimages.
+        for fe in self.elligator_ristretto_flavor_inverse().iter() {
+            let is_preimage = fe.is_some();
+            let bytes = fe.unwrap_or(FieldElement::ZERO).as_bytes();
+
+            let mut expected = [0u8; 32];
+            expected.copy_from_slice(&D::digest(&bytes[8..24]));
+            expected[8..24].copy_from_slice(&bytes[8..24]);
+            expected[0] &= 0b1111_1110;
+            expected[31] &= 0b0011_1111;
+
+            let is_encoding = is_preimage & expected.ct_eq(&bytes);
+            for (r, b) in result.iter_mut().zip(bytes[8..24].iter()) {
+                r.conditional_assign(b, is_encoding);
+            }
+            n_found += is_encoding.unwrap_u8();
+        }
+
+        // Two distinct payloads would need colliding hashes in 126 bits, which happens with
+        // negligible probability.
+        if n_found == 1 {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Compute the nonnegative field elements \\(r\\) such that
+    /// `RistrettoPoint::elligator_ristretto_flavor(r) == self`. There are at most eight of them.
+    /// (The negative preimages are their negations.)
+    pub(crate) fn elligator_ristretto_flavor_inverse(&self) -> [CtOption<FieldElement>; 8] {
+        // Elligator2 computes a point from a field element in two steps: first it computes a
+        // point (s, t) on the Jacobi quartic, and then the corresponding even point on the
+        // Edwards curve.
+        //
+        // We invert in three steps. Any Ristretto point has four representatives as even
+        // Edwards points. For each of those, there are two points on the Jacobi quartic that
+        // map to it, namely (s, t) and its dual (-s, -t). Each of those eight points on the
+        // Jacobi quartic might have an Elligator2 preimage.
+        let jcs = self.to_jacobi_quartic_ristretto();
+
+        let mut preimages = [CtOption::new(FieldElement::ZERO, Choice::from(0)); 8];
+        for (i, jc) in jcs.iter().enumerate() {
+            preimages[2 * i] = jc.e_inv_positive();
+            preimages[2 * i + 1] = jc.dual().e_inv_positive();
+        }
+        preimages
+    }
+
+    /// Find a point on the Jacobi quartic associated to each of the four points Ristretto
+    /// equivalent to `self`.
+    ///
+    /// There is one exception: for \\((0, -1)\\) there is no point on the quartic, and so we
+    /// repeat one on the quartic equivalent to \\((0, 1)\\).
+    fn to_jacobi_quartic_ristretto(self) -> [JacobiPoint; 4] {
+        let x2 = self.0.X.square(); // X^2
+        let y2 = self.0.Y.square(); // Y^2
+        let y4 = y2.square(); // Y^4
+        let z2 = self.0.Z.square(); // Z^2
+        let z_min_y = &self.0.Z - &self.0.Y; // Z - Y
+        let z_pl_y = &self.0.Z + &self.0.Y; // Z + Y
+        let z2_min_y2 = &z2 - &y2; // Z^2 - Y^2
+
+        // gamma := 1/sqrt( Y^4 X^2 (Z^2 - Y^2) )
+        let (_, gamma) = (&(&y4 * &x2) * &z2_min_y2).invsqrt();
+
+        let den = &gamma * &y2;
+
+        let s_over_x = &den * &z_min_y;
+        let sp_over_xp = &den * &z_pl_y;
+
+        let s0 = &s_over_x * &self.0.X;
+        let s1 = &(-&sp_over_xp) * &self.0.X;
+
+        // t_0 := -2/sqrt(-d-1) * Z * sOverX
+        // t_1 := -2/sqrt(-d-1) * Z * spOverXp
+        //
+        // where -2/sqrt(-d-1) = -2/sqrt(a-d) is the same as sqrt(-486664).
+        let tmp = &constants::SQRT_MINUS_APLUS2 * &self.0.Z;
+        let mut t0 = &tmp * &s_over_x;
+        let mut t1 = &tmp * &sp_over_xp;
+
+        // den := -1/sqrt(1+d) (Y^2 - Z^2) gamma
+        let den = &(&(-&z2_min_y2) * &constants::MINVSQRT_ONE_PLUS_D) * &gamma;
+
+        // Same as before but with the substitution (X, Y, Z) = (Y, X, i*Z)
+        let iz = &constants::SQRT_M1 * &self.0.Z; // iZ
+        let iz_min_x = &iz - &self.0.X; // iZ - X
+        let iz_pl_x = &iz + &self.0.X; // iZ + X
+
+        let s_over_y = &den * &iz_min_x;
+        let sp_over_yp = &den * &iz_pl_x;
+
+        let mut s2 = &s_over_y * &self.0.Y;
+        let mut s3 = &(-&sp_over_yp) * &self.0.Y;
+
+        // t_2 := -2/sqrt(-d-1) * i*Z * sOverY
+        // t_3 := -2/sqrt(-d-1) * i*Z * spOverYp
+        let tmp = &constants::SQRT_MINUS_APLUS2 * &iz;
+        let mut t2 = &tmp * &s_over_y;
+        let mut t3 = &tmp * &sp_over_yp;
+
+        // Special case: X=0 or Y=0. Then return
+        //
+        //  (0,1)   (1,-2i/sqrt(-d-1))   (-1,-2i/sqrt(-d-1))
+        //
+        // Note that if X=0 or Y=0, then s_i = t_i = 0.
+        let x_or_y_is_zero = self.0.X.is_zero() | self.0.Y.is_zero();
+        t0.conditional_assign(&FieldElement::ONE, x_or_y_is_zero);
+        t1.conditional_assign(&FieldElement::ONE, x_or_y_is_zero);
+        t2.conditional_assign(&constants::MIDOUBLE_INVSQRT_A_MINUS_D, x_or_y_is_zero);
+        t3.conditional_assign(&constants::MIDOUBLE_INVSQRT_A_MINUS_D, x_or_y_is_zero);
+        s2.conditional_assign(&FieldElement::ONE, x_or_y_is_zero);
+        s3.conditional_assign(&FieldElement::MINUS_ONE, x_or_y_is_zero);
+
+        [
+            JacobiPoint { S: s0, T: t0 },
+            JacobiPoint { S: s1, T: t1 },
+            JacobiPoint { S: s2, T: t2 },
+            JacobiPoint { S: s3, T: t3 },
+        ]
+    }
+}
+
+/// A point \\((s, t)\\) on the Jacobi quartic associated to the Edwards curve.
+#[derive(Copy, Clone)]
+struct JacobiPoint {
+    S: FieldElement,
+    T: FieldElement,
+}
+
+impl JacobiPoint {
+    /// Elligator2 is defined in two steps: first a function \\(e\\) maps a field element
+    /// \\(r\\) to a point on the Jacobi quartic, and then this point is mapped to the Edwards
+    /// curve. Since \\(e(r) = e(-r)\\), preimages come in pairs.
+    ///
+    /// Compute the nonnegative preimage of this point under \\(e\\), if it exists.
+    fn e_inv_positive(&self) -> CtOption<FieldElement> {
+        let mut out = FieldElement::ZERO;
+
+        // Special case: s = 0. If s is zero, either t = 1 or t = -1.
+        // If t = 1, then sqrt(i*d) is the preimage. Otherwise it's 0.
+        let s_is_zero = self.S.is_zero();
+        let t_equals_one = self.T.ct_eq(&FieldElement::ONE);
+        out.conditional_assign(&constants::SQRT_ID, t_equals_one);
+        let mut is_defined = s_is_zero;
+        let mut done = s_is_zero;
+
+        // a := (t+1) (d+1)/(d-1)
+        let a = &(&self.T + &FieldElement::ONE) * &constants::DP1_OVER_DM1;
+        let a2 = a.square();
+
+        // y := 1/sqrt(i (s^4 - a^2)).
+        let s2 = self.S.square();
+        let s4 = s2.square();
+        let invSqY = &(&s4 - &a2) * &constants::SQRT_M1;
+
+        // There is no preimage if the square root of i*(s^4-a^2) does not exist.
+        let (sq, y) = invSqY.invsqrt();
+        is_defined |= sq;
+        done |= !sq;
+
+        // x := (a + sign(s)*s^2) y
+        let mut pms2 = s2;
+        pms2.conditional_negate(self.S.is_negative());
+        let mut x = &(&a + &pms2) * &y;
+        // Always pick the nonnegative solution
+        let x_is_negative = x.is_negative();
+        x.conditional_negate(x_is_negative);
+        out.conditional_assign(&x, !done);
+
+        CtOption::new(out, is_defined)
+    }
+
+    fn dual(&self) -> JacobiPoint {
+        JacobiPoint {
+            S: -&self.S,
+            T: -&self.T,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::edwards::EdwardsPoint;
+    use crate::ristretto::CompressedRistretto;
+
+    use rand_core::RngCore;
+    use sha2::Sha256;
+
+    /// Return the coset self + E\[4\]
+    fn xcoset4(pt: &RistrettoPoint) -> [EdwardsPoint; 4] {
+        [
+            pt.0,
+            pt.0 + constants::EIGHT_TORSION[2],
+            pt.0 + constants::EIGHT_TORSION[4],
+            pt.0 + constants::EIGHT_TORSION[6],
+        ]
+    }
+
+    /// Test vectors of the form (data, compressed encoding of `lizard_encode(data)`), from the
+    /// `testLizard()` function in `ristretto.sage`.
+    const LIZARD_KAT: &[(&str, &str)] = &[
+        (
+            "00000000000000000000000000000000",
+            "f0b7e34484f74cf00f15024b738539738646bbbe1e9bc7509a676815227e774f",
+        ),
+        (
+            "01010101010101010101010101010101",
+            "cc92e81f585afc5caac88660d8d17e9025a44489a363042123f6af0702156e65",
+        ),
+        (
+            "000102030405060708090a0b0c0d0e0f",
+            "c830573f8a8e7778671f76cdc796dc0a235cf177f197d9fcba06e84e96247444",
+        ),
+        (
+            "dddddddddddddddddddddddddddddddd",
+            "ccb60554c081841037f821fa827b6a5bc2531f80e2647f1a858611f4ccfe3056",
+        ),
+    ];
+
+    #[test]
+    fn lizard_encode_test_vectors() {
+        for (data, encoding) in LIZARD_KAT.iter() {
+            let data: [u8; 16] = hex::decode(data)
+                .expect("invalid hex")
+                .try_into()
+                .expect("expected 16 bytes");
+            let encoding = hex::decode(encoding).expect("invalid hex");
+
+            let P = RistrettoPoint::lizard_encode::<Sha256>(&data);
+            assert_eq!(P.compress().as_bytes()[..], encoding[..]);
+
+            let P = CompressedRistretto::from_slice(&encoding)
+                .expect("expected 32 bytes")
+                .decompress()
+                .expect("invalid encoding");
+            assert_eq!(P.lizard_decode::<Sha256>(), Some(data));
+        }
+    }
+
+    #[test]
+    fn lizard_roundtrip() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let mut data = [0u8; 16];
+            rng.fill_bytes(&mut data);
+
+            let P = RistrettoPoint::lizard_encode::<Sha256>(&data);
+            // Decoding must not depend on which representative of P we hold
+            for Q in xcoset4(&P).iter() {
+                assert_eq!(RistrettoPoint(*Q).lizard_decode::<Sha256>(), Some(data));
+            }
+        }
+    }
+
+    #[test]
+    fn lizard_decode_random_point() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let P = RistrettoPoint::random(&mut rng);
+            assert_eq!(P.lizard_decode::<Sha256>(), None);
+        }
+    }
+
+    /// Check that elligator_ristretto_flavor ○ elligator_ristretto_flavor_inverse ○
+    /// elligator_ristretto_flavor is the identity, on every representative of the point.
+    #[test]
+    fn elligator_inverse() {
+        let mut rng = rand::thread_rng();
+
+        for i in 0..100 {
+            let mut fe_bytes = [0u8; 32];
+            if i == 0 {
+                // First corner case: r = 0
+            } else if i == 1 {
+                // Second corner case: r = +sqrt(i*d)
+                fe_bytes = constants::SQRT_ID.as_bytes();
+            } else {
+                rng.fill_bytes(&mut fe_bytes);
+            }
+            // Make r nonnegative and less than the modulus
+            fe_bytes[0] &= 0b1111_1110;
+            fe_bytes[31] &= 0b0111_1111;
+            let fe = FieldElement::from_bytes(&fe_bytes);
+
+            let P = RistrettoPoint::elligator_ristretto_flavor(&fe);
+            for Q in xcoset4(&P).iter() {
+                let mut found = false;
+                for fe_j in RistrettoPoint(*Q)
+                    .elligator_ristretto_flavor_inverse()
+                    .iter()
+                {
+                    if bool::from(fe_j.is_some()) {
+                        let fe_j = fe_j.unwrap();
+                        assert!(!bool::from(fe_j.is_negative()));
+                        assert_eq!(RistrettoPoint::elligator_ristretto_flavor(&fe_j), P);
+                        found |= fe_j == fe;
+                    }
+                }
+                assert!(found);
+            }
+        }
+    }
+}
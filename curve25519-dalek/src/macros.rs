@@ -11,6 +11,18 @@
 
 //! Internal macros.
 
+/// Count `$n` (by default, one) operations of the kind `$counter` for the `instrumentation`
+/// feature. Expands to nothing when the feature is disabled.
+macro_rules! count_op {
+    ($counter:ident) => {
+        count_op!($counter, 1)
+    };
+    ($counter:ident, $n:expr) => {
+        #[cfg(feature = "instrumentation")]
+        crate::backend::instrumentation::$counter.add($n as usize);
+    };
+}
+
 /// Define borrow and non-borrow variants of `Add`.
 macro_rules! define_add_variants {
     (LHS = $lhs:ty, RHS = $rhs:ty, Output = $out:ty) => {
@@ -54,7 +54,7 @@ use core::{
     ops::{Mul, MulAssign},
 };
 
-use crate::constants::{APLUS2_OVER_FOUR, MONTGOMERY_A, MONTGOMERY_A_NEG};
+use crate::constants::{self, APLUS2_OVER_FOUR, MONTGOMERY_A, MONTGOMERY_A_NEG};
 use crate::edwards::{CompressedEdwardsY, EdwardsPoint};
 use crate::field::FieldElement;
 use crate::scalar::{clamp_integer, Scalar};
@@ -63,7 +63,10 @@ use crate::traits::Identity;
 
 use subtle::Choice;
 use subtle::ConstantTimeEq;
-use subtle::{ConditionallyNegatable, ConditionallySelectable};
+use subtle::{ConditionallyNegatable, ConditionallySelectable, CtOption};
+
+#[cfg(feature = "digest")]
+use digest::{crypto_common::BlockSizeUser, generic_array::typenum::U64, Digest};
 
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
@@ -250,6 +253,107 @@ impl MontgomeryPoint {
 
         CompressedEdwardsY(y_bytes).decompress()
     }
+
+    /// Map an Elligator2 representative to a point on the curve.
+    ///
+    /// The two most significant bits of `representative` are ignored, and the remaining bits
+    /// are mapped to the curve with the Elligator2 map of
+    /// [RFC 9380, section 6.7.1](https://www.rfc-editor.org/rfc/rfc9380.html#section-6.7.1).
+    /// This is the inverse of [`MontgomeryPoint::to_representative`], and is compatible with
+    /// obfs4 and with Monocypher's `crypto_elligator_map`.
+    ///
+    /// The output is not necessarily in the prime-order subgroup.
+    pub fn from_representative(representative: &[u8; 32]) -> MontgomeryPoint {
+        let mut bytes = *representative;
+        bytes[31] &= 0x3f;
+        elligator_encode(&FieldElement::from_bytes(&bytes))
+    }
+
+    /// Compute an Elligator2 representative of this point, that is, a 32-byte string which
+    /// [`MontgomeryPoint::from_representative`] maps back to `self`.
+    ///
+    /// Only about half of all points have a representative; for the others this returns
+    /// `None`. A point with \\(u\\)-coordinate \\(0\\) is always reported as unrepresentable.
+    ///
+    /// Each representable \\(u\\)-coordinate has two representatives, one for each of the points
+    /// \\((u, \pm v)\\), and the low bit of `tweak` chooses between them. The two high bits of
+    /// `tweak` are copied into the two high bits of the representative, which are otherwise
+    /// zero. This matches Monocypher's `crypto_elligator_rev`.
+    ///
+    /// The representative of a uniformly random point on the curve, computed with a uniformly
+    /// random `tweak`, is indistinguishable from 32 uniformly random bytes. **This is not the
+    /// case for points in the prime-order subgroup**, such as ordinary public keys, since an
+    /// observer can map the representative back to the curve and check the order of the point.
+    /// Keys which are meant to be hidden should be generated with
+    /// [`EdwardsPoint::mul_base_clamped_dirty`], which preserves the X25519 shared secret.
+    pub fn to_representative(&self, tweak: u8) -> CtOption<[u8; 32]> {
+        let u = FieldElement::from_bytes(&self.0);
+        let (is_representable, r) = elligator_decode(&u, Choice::from(tweak & 1));
+
+        let mut bytes = r.as_bytes();
+        bytes[31] |= tweak & 0xc0;
+        CtOption::new(bytes, is_representable)
+    }
+
+    #[cfg(feature = "digest")]
+    /// Hash a message to a point in the prime-order subgroup, using the
+    /// `curve25519_XMD:SHA-512_ELL2_RO_` suite from
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-8.5), with the hash
+    /// function `D` in place of SHA-512.
+    ///
+    /// The output is indistinguishable from a random point when `D` is SHA-512, or any other
+    /// hash with a 64-byte output that behaves like a random oracle. The domain separation tag
+    /// `dst` should be unique to the protocol and the purpose the hash is used for; see
+    /// section 3.1 of the RFC.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::montgomery::MontgomeryPoint;
+    /// use sha2::Sha512;
+    ///
+    /// # fn main() {
+    /// let dst = b"MyProtocol-V1-CS01-with-curve25519_XMD:SHA-512_ELL2_RO_";
+    /// let P = MontgomeryPoint::hash_to_curve::<Sha512>(b"Hello world", dst);
+    ///
+    /// assert!(P.to_edwards(0).unwrap().is_torsion_free());
+    /// # }
+    /// ```
+    pub fn hash_to_curve<D>(msg: &[u8], dst: &[u8]) -> MontgomeryPoint
+    where
+        D: Digest<OutputSize = U64> + BlockSizeUser,
+    {
+        // The curve25519 and edwards25519 suites differ only in the DST and in the final
+        // rational map, which is an isomorphism away from the 2-torsion point (0, 0). That point
+        // is sent to the identity instead of (0, -1), but the discrepancy is a 2-torsion point
+        // and vanishes under cofactor clearing, so we can do the group operations on the
+        // Edwards curve.
+        EdwardsPoint::hash_to_curve::<D>(msg, dst).to_montgomery()
+    }
+
+    #[cfg(feature = "digest")]
+    /// Encode a message to a point in the prime-order subgroup, using the
+    /// `curve25519_XMD:SHA-512_ELL2_NU_` suite from
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-8.5), with the hash
+    /// function `D` in place of SHA-512.
+    ///
+    /// The output is **not** uniformly distributed. Unless a protocol explicitly calls for a
+    /// nonuniform encoding, use [`MontgomeryPoint::hash_to_curve`] instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is empty.
+    pub fn encode_to_curve<D>(msg: &[u8], dst: &[u8]) -> MontgomeryPoint
+    where
+        D: Digest<OutputSize = U64> + BlockSizeUser,
+    {
+        // See the comment in `hash_to_curve`.
+        EdwardsPoint::encode_to_curve::<D>(msg, dst).to_montgomery()
+    }
 }
 
 /// Perform the Elligator2 mapping to a Montgomery point.
@@ -258,8 +362,19 @@ impl MontgomeryPoint {
 //
 // TODO Determine how much of the hash-to-group API should be exposed after the CFRG
 //      draft gets into a more polished/accepted state.
-#[allow(unused)]
 pub(crate) fn elligator_encode(r_0: &FieldElement) -> MontgomeryPoint {
+    let (u, _v) = elligator_encode_uv(r_0);
+    MontgomeryPoint(u.as_bytes())
+}
+
+/// Perform the Elligator2 mapping to an affine point \\((u, v)\\) on the Montgomery form of
+/// Curve25519.
+///
+/// The sign of \\(v\\) is chosen as in the `map_to_curve_elligator2` function of
+/// [RFC 9380][rfc9380], so that this agrees with the `curve25519_XMD:SHA-512_ELL2_*` suites.
+///
+/// [rfc9380]: https://www.rfc-editor.org/rfc/rfc9380.html#section-6.7.1
+pub(crate) fn elligator_encode_uv(r_0: &FieldElement) -> (FieldElement, FieldElement) {
     let one = FieldElement::ONE;
     let d_1 = &one + &r_0.square2(); /* 2r^2 */
 
@@ -271,14 +386,55 @@ pub(crate) fn elligator_encode(r_0: &FieldElement) -> MontgomeryPoint {
     let inner = &(d_sq + &au) + &one;
     let eps = &d * &inner; /* eps = d^3 + Ad^2 + d */
 
-    let (eps_is_sq, _eps) = FieldElement::sqrt_ratio_i(&eps, &one);
+    let (eps_is_sq, eps_sqrt) = FieldElement::sqrt_ratio_i(&eps, &one);
 
     let zero = FieldElement::ZERO;
     let Atemp = FieldElement::conditional_select(&MONTGOMERY_A, &zero, eps_is_sq); /* 0, or A if nonsquare*/
     let mut u = &d + &Atemp; /* d, or d+A if nonsquare */
     u.conditional_negate(!eps_is_sq); /* d, or -d-A if nonsquare */
 
-    MontgomeryPoint(u.as_bytes())
+    // If eps is square, v = -sqrt(eps). Otherwise, eps_sqrt = sqrt(i*eps) and the curve equation
+    // evaluated at u = -d-A is 2r^2 * eps, whose square root is r * sqrt(i*eps) * (1 - i), since
+    // (1 - i)^2 = -2i. In that case we take the nonnegative root.
+    let one_minus_i = &one - &constants::SQRT_M1;
+    let mut v = &(r_0 * &eps_sqrt) * &one_minus_i;
+    v.conditional_negate(v.is_negative());
+    v.conditional_assign(&-&eps_sqrt, eps_is_sq);
+
+    (u, v)
+}
+
+/// Invert the Elligator2 map: find a field element \\(r\\) which
+/// [`elligator_encode_uv`] sends to the point \\((u, v)\\), where only the sign of
+/// \\(v\\) is given.
+///
+/// Both \\(r\\) and \\(-r\\) are sent to the same point, and the one returned is the
+/// one in \\(\[0, (p-1)/2\]\\), so that the two high bits of its encoding are zero.
+///
+/// # Return
+///
+/// * `(Choice(1), r)` if \\((u, v)\\) is in the image of the map and \\( u \neq 0 \\);
+/// * `(Choice(0), garbage)` otherwise.
+pub(crate) fn elligator_decode(u: &FieldElement, v_is_negative: Choice) -> (Choice, FieldElement) {
+    // The map sends r to u = d when eps = d^3 + Ad^2 + d is square, with v negative, and to
+    // u = -d - A otherwise, with v nonnegative, where d = -A / (1 + 2r^2). Solving for r gives
+    //
+    //     r^2 = -(u + A) / 2u      if v is negative,
+    //     r^2 = -u / 2(u + A)      otherwise,
+    //
+    // and both have a root exactly when -2u(u + A) is a nonzero square.
+    let u_plus_A = u + &MONTGOMERY_A;
+    let t = u * &u_plus_A;
+    let (is_square, inv_sqrt) = FieldElement::sqrt_ratio_i(&FieldElement::ONE, &-&(&t + &t));
+
+    let numerator = FieldElement::conditional_select(u, &u_plus_A, v_is_negative);
+    let mut r = &numerator * &inv_sqrt;
+
+    // 2r is odd exactly when r > (p-1)/2.
+    let r_doubled = &r + &r;
+    r.conditional_negate(r_doubled.is_negative());
+
+    (is_square, r)
 }
 
 /// A `ProjectivePoint` holds a point on the projective line
@@ -439,6 +595,9 @@ mod test {
 
     use rand_core::{CryptoRng, RngCore};
 
+    #[cfg(feature = "digest")]
+    use crate::field::fe_from_rfc_hex;
+
     #[test]
     fn identity_in_different_coordinates() {
         let id_projective = ProjectivePoint::identity();
@@ -663,4 +822,268 @@ mod test {
         let eg = elligator_encode(&fe);
         assert_eq!(eg.to_bytes(), zero);
     }
+
+    /// Key pairs generated by the obfs4 fork of agl/ed25519, of the form (secret key, public
+    /// key with a low-order component, representative computed with a zero tweak).
+    const OBFS4_REPRESENTATIVE_KAT: &[(&str, &str, &str)] = &[
+        (
+            "b531f4243aa4a013f0f87a2eaaec47807844a2f375d40b774e824d37a196b2b6",
+            "04a47c7903661acf03cd71e5400c8b96650bb3620d2ae91b674713ca5f2ac673",
+            "f30130eb1c192cda48a503932c8751232fd784ab7792acba499807e78084a909",
+        ),
+        (
+            "d63f245d00c57683e8f3f7174b2601aa89319d39823e42aa388fb4f349b8df16",
+            "3741b60e375affc17d0dba931bb0f5f4540c31c67a979c3399596103283dd27d",
+            "3a50a823f482a7af1ac898446850dd643b9a68b530df4cebe7d9f108ebe8933b",
+        ),
+        (
+            "2b6b4888ec2d23748f708627c1a9260b0f10dd2fbc941e44cd578d69760d9873",
+            "d8db37c0fafe81757adcf4f580daff6f68b200a4a38965a4a71ced905e67ee26",
+            "ed3b270440c2c9d6630bd2b92fd07ede4b8ac3881a286bc9d5f225e35e1d5a31",
+        ),
+        (
+            "9228b2e3a95462b6d9f32cc326c3c55107972e9cb426301d33861bec15049b65",
+            "9d8ef0820c3ed0b0c7d29e178eaf31431ca4ee1e97cdab08ea6c85946a3e5e6b",
+            "b918077e9e1283c0cc44055a28b97ea131e8517983ed033d60e99b818f55fe00",
+        ),
+        (
+            "21af4b82c709c6dbf0a1aa28b7a045ea6a20448ec36c9f5f6eadf60c9a71ee66",
+            "41b49d99d7490aae2c774893d2f24147c353f4179ef318c1954899eb93ba7316",
+            "e1d2992fce97a72d40483f940aa1156d34eea4e5789336155d718f0d74eb9e27",
+        ),
+        (
+            "465fd0ac29e17ecadf922676a99a40e59ef20ed7d964663fce6d09cfe247599e",
+            "9b85e75bfe129adf7e28d2ac1a8a2a186259c91e9afe5b60497f59fe48a61363",
+            "cfa4592dc98b4625c70bcc5f5b39611043436e4d8818e977ae479e2321b4e731",
+        ),
+    ];
+
+    /// Secret keys whose public keys obfs4 reports as unrepresentable.
+    const OBFS4_UNREPRESENTABLE_KAT: &[&str] = &[
+        "e3457a03d99b91ab2860470a9501e03f30f4f91c9655c5d2700e43fc07262f3f",
+        "f73f18d545882268e7ca793f717d175acb8c628af8de8445b0c5a0c4cca7fab8",
+        "3e0b46dc90be39c8abc65231caabfa935ee055f83c4055b89fc189715c10c9fa",
+        "8d2a6143e68ad022f544e13a21954a0615eb544e072399f31a4535c6bb5a4343",
+        "42e01260c570a1be859ecce34b029acf600b7ef520e864a7c75cd122e4eb650b",
+        "270eece8fa7e73fd071c4e7deefcd4f58553fa2572ef9b750605a48dfea959c0",
+        "5f4dd2d5e3a4d3288a61b5f91f10e89b16ed1ae4496a06b15ae1b0fbd2b8c283",
+        "01f7ca332f44b4467e4b336c16fb59dae75e2a81eef1f54a3a6352ab7c8cdb3c",
+    ];
+
+    fn bytes_from_hex(hex_str: &str) -> [u8; 32] {
+        hex::decode(hex_str)
+            .expect("invalid hex")
+            .try_into()
+            .expect("expected 32 bytes")
+    }
+
+    #[test]
+    fn elligator_representative_obfs4_test_vectors() {
+        for (i, (secret, public, representative)) in OBFS4_REPRESENTATIVE_KAT.iter().enumerate() {
+            let public = MontgomeryPoint(bytes_from_hex(public));
+            let representative = bytes_from_hex(representative);
+
+            let P = EdwardsPoint::mul_base_clamped_dirty(bytes_from_hex(secret)).to_montgomery();
+            assert_eq!(P, public, "failed on test vector {}", i);
+            assert_eq!(
+                public.to_representative(0).unwrap(),
+                representative,
+                "failed on test vector {}",
+                i
+            );
+            assert_eq!(
+                MontgomeryPoint::from_representative(&representative),
+                public,
+                "failed on test vector {}",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn elligator_unrepresentable_obfs4_test_vectors() {
+        for secret in OBFS4_UNREPRESENTABLE_KAT.iter() {
+            let P = EdwardsPoint::mul_base_clamped_dirty(bytes_from_hex(secret));
+            assert!(bool::from(P.to_montgomery().to_representative(0).is_none()));
+            assert!(bool::from(P.to_montgomery().to_representative(1).is_none()));
+            assert!(bool::from(P.to_representative(0).is_none()));
+        }
+    }
+
+    #[test]
+    fn elligator_representative_roundtrip() {
+        let mut csprng = rand_core::OsRng;
+
+        for _ in 0..100 {
+            let mut r = [0u8; 32];
+            csprng.fill_bytes(&mut r);
+            let P = MontgomeryPoint::from_representative(&r);
+
+            // Both choices of v give a representative of P, and one of them is r up to sign.
+            let r_0 = P.to_representative(0x00).unwrap();
+            let r_1 = P.to_representative(0xc1).unwrap();
+            assert_eq!(MontgomeryPoint::from_representative(&r_0), P);
+            assert_eq!(MontgomeryPoint::from_representative(&r_1), P);
+            assert_eq!(r_0[31] & 0xc0, 0x00);
+            assert_eq!(r_1[31] & 0xc0, 0xc0);
+
+            let r_fe = {
+                r[31] &= 0x3f;
+                FieldElement::from_bytes(&r)
+            };
+            let r_1_fe = {
+                let mut r_1 = r_1;
+                r_1[31] &= 0x3f;
+                FieldElement::from_bytes(&r_1)
+            };
+            let r_0_fe = FieldElement::from_bytes(&r_0);
+            assert!(r_fe == r_0_fe || r_fe == -&r_0_fe || r_fe == r_1_fe || r_fe == -&r_1_fe);
+        }
+    }
+
+    #[test]
+    fn elligator_representative_of_zero() {
+        assert_eq!(
+            MontgomeryPoint::from_representative(&[0u8; 32]),
+            MontgomeryPoint([0u8; 32])
+        );
+        assert!(bool::from(
+            MontgomeryPoint([0u8; 32]).to_representative(0).is_none()
+        ));
+    }
+
+    /// Test vectors for `curve25519_XMD:SHA-512_ELL2_RO_` from RFC 9380, appendix J.4.1,
+    /// of the form (msg, P.x).
+    #[cfg(feature = "digest")]
+    const RFC9380_HASH_TO_CURVE_KAT: &[(&[u8], &str)] = &[
+        (
+            b"",
+            "2de3780abb67e861289f5749d16d3e217ffa722192d16bbd9d1bfb9d112b98c0",
+        ),
+        (
+            b"abc",
+            "2b4419f1f2d48f5872de692b0aca72cc7b0a60915dd70bde432e826b6abc526d",
+        ),
+        (
+            b"abcdef0123456789",
+            "68ca1ea5a6acf4e9956daa101709b1eee6c1bb0df1de3b90d4602382a104c036",
+        ),
+        (
+            b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+            "096e9c8bae6c06b554c1ee69383bb0e82267e064236b3a30608d4ed20b73ac5a",
+        ),
+        (
+            b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "1bc61845a138e912f047b5e70ba9606ba2a447a4dade024c8ef3dd42b7bbc5fe",
+        ),
+    ];
+
+    /// Test vectors for `curve25519_XMD:SHA-512_ELL2_NU_` from RFC 9380, appendix J.4.2,
+    /// of the form (msg, P.x).
+    #[cfg(feature = "digest")]
+    const RFC9380_ENCODE_TO_CURVE_KAT: &[(&[u8], &str)] = &[
+        (
+            b"",
+            "1bb913f0c9daefa0b3375378ffa534bda5526c97391952a7789eb976edfe4d08",
+        ),
+        (
+            b"abc",
+            "7c22950b7d900fa866334262fcaea47a441a578df43b894b4625c9b450f9a026",
+        ),
+        (
+            b"abcdef0123456789",
+            "31ad08a8b0deeb2a4d8b0206ca25f567ab4e042746f792f4b7973f3ae2096c52",
+        ),
+        (
+            b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
+            qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+            "027877759d155b1997d0d84683a313eb78bdb493271d935b622900459d52ceaa",
+        ),
+        (
+            b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "5fd892c0958d1a75f54c3182a18d286efab784e774d1e017ba2fb252998b5dc1",
+        ),
+    ];
+
+    /// Intermediate values from RFC 9380, appendix J.4.2, of the form (u, Q.x, Q.y), where
+    /// Q is the output of the Elligator2 map before cofactor clearing.
+    #[cfg(feature = "digest")]
+    const RFC9380_MAP_TO_CURVE_KAT: &[(&str, &str, &str)] = &[
+        (
+            "608d892b641f0328523802a6603427c26e55e6f27e71a91a478148d45b5093cd",
+            "51125222da5e763d97f3c10fcc92ea6860b9ccbbd2eb1285728f566721c1e65b",
+            "343d2204f812d3dfc5304a5808c6c0d81a903a5d228b342442aa3c9ba5520a3d",
+        ),
+        (
+            "46f5b22494bfeaa7f232cc8d054be68561af50230234d7d1d63d1d9abeca8da5",
+            "7d56d1e08cb0ccb92baf069c18c49bb5a0dcd927eff8dcf75ca921ef7f3e6eeb",
+            "404d9a7dc25c9c05c44ab9a94590e7c3fe2dcec74533a0b24b188a5d5dacf429",
+        ),
+        (
+            "235fe40c443766ce7e18111c33862d66c3b33267efa50d50f9e8e5d252a40aaa",
+            "3fbe66b9c9883d79e8407150e7c2a1c8680bee496c62fabe4619a72b3cabe90f",
+            "08ec476147c9a0a3ff312d303dbbd076abb7551e5fce82b48ab14b433f8d0a7b",
+        ),
+        (
+            "001e92a544463bda9bd04ddbe3d6eed248f82de32f522669efc5ddce95f46f5b",
+            "227e0bb89de700385d19ec40e857db6e6a3e634b1c32962f370d26f84ff19683",
+            "5f86ff3851d262727326a32c1bf7655a03665830fa7f1b8b1e5a09d85bc66e4a",
+        ),
+        (
+            "1a68a1af9f663592291af987203393f707305c7bac9c8d63d6a729bdc553dc19",
+            "3bcd651ee54d5f7b6013898aab251ee8ecc0688166fce6e9548d38472f6bd196",
+            "1bb36ad9197299f111b4ef21271c41f4b7ecf5543db8bb5931307ebdb2eaa465",
+        ),
+    ];
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn hash_to_curve_rfc9380_test_vectors() {
+        let dst = b"QUUX-V01-CS02-with-curve25519_XMD:SHA-512_ELL2_RO_";
+        for (i, (msg, x)) in RFC9380_HASH_TO_CURVE_KAT.iter().enumerate() {
+            let P = MontgomeryPoint::hash_to_curve::<sha2::Sha512>(msg, dst);
+            assert_eq!(
+                P.to_bytes(),
+                fe_from_rfc_hex(x).as_bytes(),
+                "failed on test vector {}",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn encode_to_curve_rfc9380_test_vectors() {
+        let dst = b"QUUX-V01-CS02-with-curve25519_XMD:SHA-512_ELL2_NU_";
+        for (i, (msg, x)) in RFC9380_ENCODE_TO_CURVE_KAT.iter().enumerate() {
+            let P = MontgomeryPoint::encode_to_curve::<sha2::Sha512>(msg, dst);
+            assert_eq!(
+                P.to_bytes(),
+                fe_from_rfc_hex(x).as_bytes(),
+                "failed on test vector {}",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn elligator_encode_uv_rfc9380_test_vectors() {
+        for (i, (r, x, y)) in RFC9380_MAP_TO_CURVE_KAT.iter().enumerate() {
+            let (u, v) = elligator_encode_uv(&fe_from_rfc_hex(r));
+            assert_eq!(u, fe_from_rfc_hex(x), "failed on test vector {}", i);
+            assert_eq!(v, fe_from_rfc_hex(y), "failed on test vector {}", i);
+        }
+    }
 }
@@ -64,8 +64,9 @@
 //! Encoding is done by converting to and from a `CompressedRistretto`
 //! struct, which is a typed wrapper around `[u8; 32]`.
 //!
-//! The encoding is not batchable, but it is possible to
-//! double-and-encode in a batch using
+//! The encoding is not batchable: `RistrettoPoint::compress_batch`
+//! is a convenience which costs one inverse square root per point.
+//! However, it is possible to double-and-encode in a batch using
 //! `RistrettoPoint::double_and_compress_batch`.
 //!
 //! ## Equality Testing
@@ -97,7 +98,8 @@
 //!
 //! * the `*` operator between a `Scalar` and a
 //! `RistrettoBasepointTable`, which performs constant-time fixed-base
-//! scalar multiplication;
+//! scalar multiplication (the larger `RistrettoBasepointTableRadix32`,
+//! `Radix64`, `Radix128` and `Radix256` tables trade memory for speed);
 //!
 //! * an implementation of the
 //! [`MultiscalarMul`](../traits/trait.MultiscalarMul.html) trait for
@@ -161,6 +163,8 @@
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+use cfg_if::cfg_if;
+
 use core::array::TryFromSliceError;
 use core::borrow::Borrow;
 use core::fmt::Debug;
@@ -175,9 +179,11 @@ use rand_core::CryptoRngCore;
 #[cfg(feature = "digest")]
 use digest::generic_array::typenum::U64;
 #[cfg(feature = "digest")]
-use digest::Digest;
+use digest::{crypto_common::BlockSizeUser, Digest};
 
 use crate::constants;
+#[cfg(feature = "digest")]
+use crate::expand_message::{ExpandMessage, ExpandMsgXmd};
 use crate::field::FieldElement;
 
 #[cfg(feature = "group")]
@@ -195,9 +201,17 @@ use subtle::ConstantTimeEq;
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
-#[cfg(feature = "precomputed-tables")]
-use crate::edwards::EdwardsBasepointTable;
+#[cfg(feature = "alloc")]
+use crate::edwards::EdwardsMultiscalarAccumulator;
 use crate::edwards::EdwardsPoint;
+use crate::edwards::EdwardsPointTable;
+#[cfg(feature = "precomputed-tables")]
+use crate::edwards::TableEncodingError;
+#[cfg(feature = "precomputed-tables")]
+use crate::edwards::{
+    EdwardsBasepointTable, EdwardsBasepointTableRadix128, EdwardsBasepointTableRadix256,
+    EdwardsBasepointTableRadix32, EdwardsBasepointTableRadix64,
+};
 
 use crate::scalar::Scalar;
 
@@ -267,6 +281,70 @@ impl CompressedRistretto {
             Some(res)
         }
     }
+
+    /// Attempt to decompress to a `RistrettoPoint`, using a precomputed `hint` in place of the
+    /// inverse square root that [`CompressedRistretto::decompress`] computes.
+    ///
+    /// The hint is the canonical encoding of the nonnegative \\(I\\) with
+    /// \\(I^2 \cdot v u_2^2 = 1\\), in the notation of the decoding procedure of
+    /// [RFC 9496 §4.3.1](https://www.rfc-editor.org/rfc/rfc9496#section-4.3.1), and can be
+    /// computed with [`CompressedRistretto::decompress_hint`]. Checking it costs a few
+    /// multiplications, which is much cheaper than computing it in environments, such as zkVMs,
+    /// where exponentiation is expensive.
+    ///
+    /// # Return
+    ///
+    /// - `Some(RistrettoPoint)` if `self` was the canonical encoding of a point and `hint` is
+    ///   the correct hint for it, in which case the point is the same as returned by
+    ///   `decompress`;
+    ///
+    /// - `None` if `self` was not the canonical encoding of a point, or if `hint` is wrong.
+    pub fn decompress_with_hint(&self, hint: &[u8; 32]) -> Option<RistrettoPoint> {
+        let (s_encoding_is_canonical, s_is_negative, s) = decompress::step_1(self);
+
+        if (!s_encoding_is_canonical | s_is_negative).into() {
+            return None;
+        }
+
+        let (u1, u2, v, w) = decompress::step_2_ratio(&s);
+
+        // The hint must be the nonnegative, canonically encoded inverse square root of w, as
+        // returned by FieldElement::invsqrt. Since w is zero exactly when there is no inverse
+        // square root, this fails for every hint when decompress fails at this step.
+        let I = FieldElement::from_bytes(hint);
+        let hint_is_canonical = I.as_bytes().ct_eq(hint);
+        let ok =
+            hint_is_canonical & !I.is_negative() & (&I.square() * &w).ct_eq(&FieldElement::ONE);
+
+        let (t_is_negative, y_is_zero, res) = decompress::step_2_finish(&s, &u1, &u2, &v, &I);
+
+        if (!ok | t_is_negative | y_is_zero).into() {
+            None
+        } else {
+            Some(res)
+        }
+    }
+
+    /// Compute natively the hint accepted by [`CompressedRistretto::decompress_with_hint`].
+    ///
+    /// Returns `None` if `self` was not the canonical encoding of a point.
+    pub fn decompress_hint(&self) -> Option<[u8; 32]> {
+        let (s_encoding_is_canonical, s_is_negative, s) = decompress::step_1(self);
+
+        if (!s_encoding_is_canonical | s_is_negative).into() {
+            return None;
+        }
+
+        let (u1, u2, v, w) = decompress::step_2_ratio(&s);
+        let (ok, I) = w.invsqrt();
+        let (t_is_negative, y_is_zero, _) = decompress::step_2_finish(&s, &u1, &u2, &v, &I);
+
+        if (!ok | t_is_negative | y_is_zero).into() {
+            None
+        } else {
+            Some(I.as_bytes())
+        }
+    }
 }
 
 mod decompress {
@@ -293,6 +371,18 @@ mod decompress {
     }
 
     pub(super) fn step_2(s: FieldElement) -> (Choice, Choice, Choice, RistrettoPoint) {
+        let (u1, u2, v, w) = step_2_ratio(&s);
+        let (ok, I) = w.invsqrt(); // 1/sqrt(v*u_2²)
+        let (t_is_negative, y_is_zero, res) = step_2_finish(&s, &u1, &u2, &v, &I);
+
+        (ok, t_is_negative, y_is_zero, res)
+    }
+
+    /// Compute \\(u_1, u_2, v\\), and the value \\(v u_2^2\\) whose inverse square root is
+    /// needed to finish step 2.
+    pub(super) fn step_2_ratio(
+        s: &FieldElement,
+    ) -> (FieldElement, FieldElement, FieldElement, FieldElement) {
         // Step 2.  Compute (X:Y:Z:T).
         let one = FieldElement::ONE;
         let ss = s.square();
@@ -302,25 +392,36 @@ mod decompress {
 
         // v == ad(1+as²)² - (1-as²)²            where d=-121665/121666
         let v = &(&(-&constants::EDWARDS_D) * &u1.square()) - &u2_sqr;
+        let w = &v * &u2_sqr;
 
-        let (ok, I) = (&v * &u2_sqr).invsqrt(); // 1/sqrt(v*u_2²)
+        (u1, u2, v, w)
+    }
+
+    /// Finish step 2, given `I` \\(= 1/\sqrt{v u_2^2}\\).
+    pub(super) fn step_2_finish(
+        s: &FieldElement,
+        u1: &FieldElement,
+        u2: &FieldElement,
+        v: &FieldElement,
+        I: &FieldElement,
+    ) -> (Choice, Choice, RistrettoPoint) {
+        let one = FieldElement::ONE;
 
-        let Dx = &I * &u2; // 1/sqrt(v)
-        let Dy = &I * &(&Dx * &v); // 1/u2
+        let Dx = I * u2; // 1/sqrt(v)
+        let Dy = I * &(&Dx * v); // 1/u2
 
         // x == | 2s/sqrt(v) | == + sqrt(4s²/(ad(1+as²)² - (1-as²)²))
-        let mut x = &(&s + &s) * &Dx;
+        let mut x = &(s + s) * &Dx;
         let x_neg = x.is_negative();
         x.conditional_negate(x_neg);
 
         // y == (1-as²)/(1+as²)
-        let y = &u1 * &Dy;
+        let y = u1 * &Dy;
 
         // t == ((1+as²) sqrt(4s²/(ad(1+as²)² - (1-as²)²)))/(1-as²)
         let t = &x * &y;
 
         (
-            ok,
             t.is_negative(),
             y.is_zero(),
             RistrettoPoint(EdwardsPoint {
@@ -521,6 +622,46 @@ impl RistrettoPoint {
         CompressedRistretto(s.as_bytes())
     }
 
+    /// Compress a batch of points using the Ristretto encoding, so that
+    /// the output is exactly `P.compress()` for each input point `P`.
+    ///
+    /// Unlike affine normalization, the Ristretto encoding cannot share
+    /// work between points: each encoding requires an inverse square
+    /// root, and there is no analogue of Montgomery's batch inversion
+    /// trick for square roots. The encoding needs no other inversion, so
+    /// this costs the same as compressing each point separately. When
+    /// encoding the doubles of the points is acceptable, use
+    /// [`RistrettoPoint::double_and_compress_batch`], which needs only a
+    /// single inversion for the whole batch.
+    ///
+    #[cfg_attr(feature = "rand_core", doc = "```")]
+    #[cfg_attr(not(feature = "rand_core"), doc = "```ignore")]
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use rand_core::OsRng;
+    ///
+    /// # // Need fn main() here in comment so the doctest compiles
+    /// # // See https://doc.rust-lang.org/book/documentation.html#documentation-as-tests
+    /// # fn main() {
+    /// let mut rng = OsRng;
+    ///
+    /// let points: Vec<RistrettoPoint> =
+    ///     (0..32).map(|_| RistrettoPoint::random(&mut rng)).collect();
+    ///
+    /// let compressed = RistrettoPoint::compress_batch(&points);
+    ///
+    /// for (P, P_compressed) in points.iter().zip(compressed.iter()) {
+    ///     assert_eq!(*P_compressed, P.compress());
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn compress_batch<'a, I>(points: I) -> Vec<CompressedRistretto>
+    where
+        I: IntoIterator<Item = &'a RistrettoPoint>,
+    {
+        points.into_iter().map(RistrettoPoint::compress).collect()
+    }
+
     /// Double-and-compress a batch of points.  The Ristretto encoding
     /// is not batchable, since it requires an inverse square root.
     ///
@@ -773,6 +914,48 @@ impl RistrettoPoint {
         RistrettoPoint::from_uniform_bytes(&output_bytes)
     }
 
+    #[cfg(feature = "digest")]
+    /// Hash a message to a `RistrettoPoint`, as specified by the `hash_to_ristretto255`
+    /// function of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#appendix-B) and
+    /// [RFC 9496](https://www.rfc-editor.org/rfc/rfc9496.html#section-4.3.4).
+    ///
+    /// The message is expanded to 64 uniform bytes using `expand_message_xmd` with the hash
+    /// function `D` and the domain separation tag `dst`, and the result is passed to
+    /// [`RistrettoPoint::from_uniform_bytes`]. With `D` set to SHA-512 this is the
+    /// `ristretto255_XMD:SHA-512_R255MAP_RO_` suite, as used e.g. by the `HashToGroup`
+    /// function of RFC 9497.
+    ///
+    /// Unlike [`RistrettoPoint::hash_from_bytes`], the output is domain separated; `dst`
+    /// should be unique to the protocol and the purpose the hash is used for.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use sha2::Sha512;
+    ///
+    /// # fn main() {
+    /// let dst = b"MyProtocol-V1-CS01-with-ristretto255_XMD:SHA-512_R255MAP_RO_";
+    /// let P = RistrettoPoint::hash_to_curve::<Sha512>(b"Hello world", dst);
+    /// let Q = RistrettoPoint::hash_to_curve::<Sha512>(b"Hello world", b"another DST");
+    ///
+    /// assert_ne!(P, Q);
+    /// # }
+    /// ```
+    pub fn hash_to_curve<D>(msg: &[u8], dst: &[u8]) -> RistrettoPoint
+    where
+        D: Digest<OutputSize = U64> + BlockSizeUser,
+    {
+        let mut uniform_bytes = [0u8; 64];
+        ExpandMsgXmd::<D>::expand_message(&[msg], dst, &mut uniform_bytes);
+
+        RistrettoPoint::from_uniform_bytes(&uniform_bytes)
+    }
+
     /// Construct a `RistrettoPoint` from 64 bytes of data.
     ///
     /// If the input bytes are uniformly distributed, the resulting
@@ -1050,6 +1233,66 @@ impl VartimePrecomputedMultiscalarMul for VartimeRistrettoPrecomputation {
     }
 }
 
+/// Accumulates the terms of a variable-time multiscalar multiplication with
+/// `RistrettoPoint`s one at a time.
+///
+/// This wraps an [`EdwardsMultiscalarAccumulator`], which describes how the terms are
+/// buffered.
+///
+/// # Example
+///
+/// ```
+/// use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+/// use curve25519_dalek::ristretto::RistrettoMultiscalarAccumulator;
+/// use curve25519_dalek::ristretto::RistrettoPoint;
+/// use curve25519_dalek::scalar::Scalar;
+/// use curve25519_dalek::traits::VartimeMultiscalarMul;
+///
+/// let scalars: Vec<_> = (1..=10u64).map(Scalar::from).collect();
+/// let points: Vec<_> = (1..=10u64).map(|i| RISTRETTO_BASEPOINT_POINT * Scalar::from(i)).collect();
+///
+/// let mut accumulator = RistrettoMultiscalarAccumulator::with_capacity(4);
+/// for (scalar, point) in scalars.iter().zip(points.iter()) {
+///     accumulator.push(*scalar, *point);
+/// }
+///
+/// assert_eq!(
+///     accumulator.finish(),
+///     RistrettoPoint::vartime_multiscalar_mul(&scalars, &points),
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default)]
+pub struct RistrettoMultiscalarAccumulator(EdwardsMultiscalarAccumulator);
+
+#[cfg(feature = "alloc")]
+impl RistrettoMultiscalarAccumulator {
+    /// Create an empty accumulator which buffers up to
+    /// [`EdwardsMultiscalarAccumulator::DEFAULT_CAPACITY`] terms.
+    pub fn new() -> Self {
+        RistrettoMultiscalarAccumulator(EdwardsMultiscalarAccumulator::new())
+    }
+
+    /// Create an empty accumulator which buffers up to `capacity` terms.
+    ///
+    /// # Panics
+    ///
+    /// If `capacity` is zero.
+    pub fn with_capacity(capacity: usize) -> Self {
+        RistrettoMultiscalarAccumulator(EdwardsMultiscalarAccumulator::with_capacity(capacity))
+    }
+
+    /// Add the term `scalar * point`.
+    pub fn push(&mut self, scalar: Scalar, point: RistrettoPoint) {
+        self.0.push(scalar, point.0);
+    }
+
+    /// Return the sum of all of the pushed terms.
+    pub fn finish(self) -> RistrettoPoint {
+        RistrettoPoint(self.0.finish())
+    }
+}
+
 impl RistrettoPoint {
     /// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the
     /// Ristretto basepoint.
@@ -1062,56 +1305,282 @@ impl RistrettoPoint {
             a, &A.0, b,
         ))
     }
+
+    /// Compute `scalar * self` in variable time.
+    ///
+    /// The running time depends on both `scalar` and `self`, so this must only be used when
+    /// both are public.
+    pub fn vartime_mul(&self, scalar: &Scalar) -> RistrettoPoint {
+        RistrettoPoint(self.0.vartime_mul(scalar))
+    }
+
+    /// Compute `scalar * B` in variable time, where `B` is the Ristretto basepoint.
+    ///
+    /// The running time depends on `scalar`, so this must only be used when `scalar` is public.
+    /// With the `precomputed-tables` feature, this is the same as [`RistrettoPoint::mul_base`].
+    pub fn vartime_mul_base(scalar: &Scalar) -> RistrettoPoint {
+        RistrettoPoint(EdwardsPoint::vartime_mul_base(scalar))
+    }
 }
 
-/// A precomputed table of multiples of a basepoint, used to accelerate
-/// scalar multiplication.
+#[cfg(feature = "precomputed-tables")]
+macro_rules! impl_ristretto_basepoint_table {
+    (Name = $name:ident, EdwardsTable = $table:ident) => {
+        /// A precomputed table of multiples of a basepoint, used to accelerate
+        /// scalar multiplication.
+        ///
+        #[doc = concat!(
+            "This wraps the [`", stringify!($table), "`], so the size and cost listed ",
+            "there apply here too."
+        )]
+        ///
+        /// A radix-16 table of multiples of the Ristretto basepoint is available in the
+        /// `constants` module as `RISTRETTO_BASEPOINT_TABLE`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+        #[doc = concat!("use curve25519_dalek::ristretto::", stringify!($name), ";")]
+        /// use curve25519_dalek::scalar::Scalar;
+        ///
+        #[doc = concat!("let table = ", stringify!($name), "::create(&RISTRETTO_BASEPOINT_POINT);")]
+        ///
+        /// let a = Scalar::from(87329482u64);
+        /// assert_eq!(&a * &table, RISTRETTO_BASEPOINT_POINT * a);
+        /// ```
+        #[derive(Clone)]
+        #[repr(transparent)]
+        pub struct $name(pub(crate) $table);
+
+        impl<'a, 'b> Mul<&'b Scalar> for &'a $name {
+            type Output = RistrettoPoint;
+
+            fn mul(self, scalar: &'b Scalar) -> RistrettoPoint {
+                RistrettoPoint(&self.0 * scalar)
+            }
+        }
+
+        impl<'a, 'b> Mul<&'a $name> for &'b Scalar {
+            type Output = RistrettoPoint;
+
+            fn mul(self, basepoint_table: &'a $name) -> RistrettoPoint {
+                RistrettoPoint(self * &basepoint_table.0)
+            }
+        }
+
+        impl $name {
+            /// Create a precomputed table of multiples of the given `basepoint`.
+            pub fn create(basepoint: &RistrettoPoint) -> $name {
+                $name($table::create(&basepoint.0))
+            }
+
+            /// Get the basepoint for this table as a `RistrettoPoint`.
+            pub fn basepoint(&self) -> RistrettoPoint {
+                RistrettoPoint(self.0.basepoint())
+            }
+
+            /// Encode this table, so that it can be loaded with
+            /// [`from_bytes`](Self::from_bytes) instead of being recomputed.
+            ///
+            #[doc = concat!(
+                "The encoding is the same as that of the inner [`", stringify!($table), "`]."
+            )]
+            #[cfg(feature = "alloc")]
+            pub fn to_bytes(&self) -> Vec<u8> {
+                self.0.to_bytes()
+            }
+
+            /// Decode a table produced by [`to_bytes`](Self::to_bytes).
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the encoding is malformed, is of a different kind of table,
+            /// contains an entry which is not a point on the curve, stores a basepoint which
+            /// does not match the table, or stores a basepoint which is not a valid
+            /// representative of a Ristretto point.
+            pub fn from_bytes(bytes: &[u8]) -> Result<$name, TableEncodingError> {
+                let table = $table::from_bytes(bytes)?;
+
+                // Ristretto points are represented by the points P of 2E, which are exactly
+                // those for which 4lP is the identity.
+                let B = table.basepoint();
+                let B_4l = (B * constants::BASEPOINT_ORDER_PRIVATE).mul_by_pow_2(2);
+                if B_4l == EdwardsPoint::identity() {
+                    Ok($name(table))
+                } else {
+                    Err(TableEncodingError::InvalidBasepoint)
+                }
+            }
+        }
+
+        #[cfg(all(feature = "serde", feature = "alloc"))]
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                self.0.serialize(serializer)
+            }
+        }
+
+        #[cfg(all(feature = "serde", feature = "alloc"))]
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                $table::deserialize(deserializer).map($name)
+            }
+        }
+    };
+}
+
+cfg_if! {
+    if #[cfg(feature = "precomputed-tables")] {
+        impl_ristretto_basepoint_table! {
+            Name = RistrettoBasepointTable,
+            EdwardsTable = EdwardsBasepointTable
+        }
+        impl_ristretto_basepoint_table! {
+            Name = RistrettoBasepointTableRadix32,
+            EdwardsTable = EdwardsBasepointTableRadix32
+        }
+        impl_ristretto_basepoint_table! {
+            Name = RistrettoBasepointTableRadix64,
+            EdwardsTable = EdwardsBasepointTableRadix64
+        }
+        impl_ristretto_basepoint_table! {
+            Name = RistrettoBasepointTableRadix128,
+            EdwardsTable = EdwardsBasepointTableRadix128
+        }
+        impl_ristretto_basepoint_table! {
+            Name = RistrettoBasepointTableRadix256,
+            EdwardsTable = EdwardsBasepointTableRadix256
+        }
+
+        /// A type-alias for [`RistrettoBasepointTable`], matching the name of the
+        /// [`EdwardsBasepointTableRadix16`](crate::edwards::EdwardsBasepointTableRadix16)
+        /// it wraps.
+        pub type RistrettoBasepointTableRadix16 = RistrettoBasepointTable;
+    }
+}
+
+#[cfg(feature = "precomputed-tables")]
+macro_rules! impl_ristretto_basepoint_table_conversions {
+    (LHS = $lhs:ty, RHS = $rhs:ty) => {
+        impl<'a> From<&'a $lhs> for $rhs {
+            fn from(table: &'a $lhs) -> $rhs {
+                <$rhs>::create(&table.basepoint())
+            }
+        }
+
+        impl<'a> From<&'a $rhs> for $lhs {
+            fn from(table: &'a $rhs) -> $lhs {
+                <$lhs>::create(&table.basepoint())
+            }
+        }
+    };
+}
+
+cfg_if! {
+    if #[cfg(feature = "precomputed-tables")] {
+        // Conversions from radix 16
+        impl_ristretto_basepoint_table_conversions! {
+            LHS = RistrettoBasepointTableRadix16,
+            RHS = RistrettoBasepointTableRadix32
+        }
+        impl_ristretto_basepoint_table_conversions! {
+            LHS = RistrettoBasepointTableRadix16,
+            RHS = RistrettoBasepointTableRadix64
+        }
+        impl_ristretto_basepoint_table_conversions! {
+            LHS = RistrettoBasepointTableRadix16,
+            RHS = RistrettoBasepointTableRadix128
+        }
+        impl_ristretto_basepoint_table_conversions! {
+            LHS = RistrettoBasepointTableRadix16,
+            RHS = RistrettoBasepointTableRadix256
+        }
+
+        // Conversions from radix 32
+        impl_ristretto_basepoint_table_conversions! {
+            LHS = RistrettoBasepointTableRadix32,
+            RHS = RistrettoBasepointTableRadix64
+        }
+        impl_ristretto_basepoint_table_conversions! {
+            LHS = RistrettoBasepointTableRadix32,
+            RHS = RistrettoBasepointTableRadix128
+        }
+        impl_ristretto_basepoint_table_conversions! {
+            LHS = RistrettoBasepointTableRadix32,
+            RHS = RistrettoBasepointTableRadix256
+        }
+
+        // Conversions from radix 64
+        impl_ristretto_basepoint_table_conversions! {
+            LHS = RistrettoBasepointTableRadix64,
+            RHS = RistrettoBasepointTableRadix128
+        }
+        impl_ristretto_basepoint_table_conversions! {
+            LHS = RistrettoBasepointTableRadix64,
+            RHS = RistrettoBasepointTableRadix256
+        }
+
+        // Conversions from radix 128
+        impl_ristretto_basepoint_table_conversions! {
+            LHS = RistrettoBasepointTableRadix128,
+            RHS = RistrettoBasepointTableRadix256
+        }
+    }
+}
+
+/// A precomputed table of the multiples of an arbitrary `RistrettoPoint`, for constant-time
+/// scalar multiplication by the same point many times.
+///
+/// See [`EdwardsPointTable`] for details.
 ///
-/// A precomputed table of multiples of the Ristretto basepoint is
-/// available in the `constants` module:
 /// ```
-/// use curve25519_dalek::constants::RISTRETTO_BASEPOINT_TABLE;
+/// use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+/// use curve25519_dalek::ristretto::RistrettoPointTable;
 /// use curve25519_dalek::scalar::Scalar;
 ///
-/// let a = Scalar::from(87329482u64);
-/// let P = &a * RISTRETTO_BASEPOINT_TABLE;
+/// let P = RISTRETTO_BASEPOINT_POINT * Scalar::from(17u64);
+/// let table = RistrettoPointTable::create(&P);
+///
+/// let s = Scalar::from(31u64);
+/// assert_eq!(&table * &s, P * s);
 /// ```
-#[cfg(feature = "precomputed-tables")]
-#[derive(Clone)]
-#[repr(transparent)]
-pub struct RistrettoBasepointTable(pub(crate) EdwardsBasepointTable);
-
-#[cfg(feature = "precomputed-tables")]
-impl<'a, 'b> Mul<&'b Scalar> for &'a RistrettoBasepointTable {
-    type Output = RistrettoPoint;
+#[derive(Clone, Debug)]
+pub struct RistrettoPointTable(pub(crate) EdwardsPointTable);
 
-    fn mul(self, scalar: &'b Scalar) -> RistrettoPoint {
-        RistrettoPoint(&self.0 * scalar)
+impl RistrettoPointTable {
+    /// Create a table of multiples of `point`.
+    pub fn create(point: &RistrettoPoint) -> RistrettoPointTable {
+        RistrettoPointTable(EdwardsPointTable::create(&point.0))
     }
 }
 
-#[cfg(feature = "precomputed-tables")]
-impl<'a, 'b> Mul<&'a RistrettoBasepointTable> for &'b Scalar {
+impl Mul<&Scalar> for &RistrettoPointTable {
     type Output = RistrettoPoint;
 
-    fn mul(self, basepoint_table: &'a RistrettoBasepointTable) -> RistrettoPoint {
-        RistrettoPoint(self * &basepoint_table.0)
+    fn mul(self, scalar: &Scalar) -> RistrettoPoint {
+        RistrettoPoint(&self.0 * scalar)
     }
 }
 
-#[cfg(feature = "precomputed-tables")]
-impl RistrettoBasepointTable {
-    /// Create a precomputed table of multiples of the given `basepoint`.
-    pub fn create(basepoint: &RistrettoPoint) -> RistrettoBasepointTable {
-        RistrettoBasepointTable(EdwardsBasepointTable::create(&basepoint.0))
-    }
+impl Mul<&RistrettoPointTable> for &Scalar {
+    type Output = RistrettoPoint;
 
-    /// Get the basepoint for this table as a `RistrettoPoint`.
-    pub fn basepoint(&self) -> RistrettoPoint {
-        RistrettoPoint(self.0.basepoint())
+    fn mul(self, table: &RistrettoPointTable) -> RistrettoPoint {
+        RistrettoPoint(self * &table.0)
     }
 }
 
+// The inner `EdwardsPointTable` is zeroized when it is dropped.
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for RistrettoPointTable {}
+
 // ------------------------------------------------------------------------
 // Constant-time conditional selection
 // ------------------------------------------------------------------------
@@ -1479,6 +1948,92 @@ mod test {
         }
     }
 
+    #[test]
+    fn point_table_vs_variable_base_mul() {
+        let mut rng = OsRng;
+        let P = RistrettoPoint::random(&mut rng);
+        let table = RistrettoPointTable::create(&P);
+        for s in &[Scalar::ZERO, -Scalar::ONE, Scalar::random(&mut rng)] {
+            assert_eq!(&table * s, P * s);
+            assert_eq!(s * &table, P * s);
+        }
+    }
+
+    #[test]
+    fn vartime_mul_vs_consttime() {
+        let mut rng = OsRng;
+        let P = RistrettoPoint::random(&mut rng);
+        for s in &[Scalar::ZERO, -Scalar::ONE, Scalar::random(&mut rng)] {
+            assert_eq!(P.vartime_mul(s), P * s);
+            assert_eq!(
+                RistrettoPoint::vartime_mul_base(s),
+                RistrettoPoint::mul_base(s)
+            );
+        }
+    }
+
+    /// Test that all the basepoint table types compute the same results.
+    #[test]
+    #[cfg(feature = "precomputed-tables")]
+    fn basepoint_tables() {
+        let mut rng = OsRng;
+        let P = RistrettoPoint::random(&mut rng);
+        let a = Scalar::random(&mut rng);
+        let aP = P * a;
+
+        let table_radix16 = RistrettoBasepointTableRadix16::create(&P);
+        assert_eq!(&table_radix16 * &a, aP);
+        assert_eq!(&a * &table_radix16, aP);
+
+        let table_radix32 = RistrettoBasepointTableRadix32::from(&table_radix16);
+        assert_eq!(&table_radix32 * &a, aP);
+        let table_radix64 = RistrettoBasepointTableRadix64::from(&table_radix32);
+        assert_eq!(&table_radix64 * &a, aP);
+        let table_radix128 = RistrettoBasepointTableRadix128::from(&table_radix64);
+        assert_eq!(&table_radix128 * &a, aP);
+        let table_radix256 = RistrettoBasepointTableRadix256::from(&table_radix128);
+        assert_eq!(&table_radix256 * &a, aP);
+        assert_eq!(table_radix256.basepoint(), P);
+
+        let table_radix16 = RistrettoBasepointTableRadix16::from(&table_radix256);
+        assert_eq!(&a * &table_radix16, aP);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "precomputed-tables"))]
+    fn basepoint_table_bytes_roundtrip() {
+        let mut rng = OsRng;
+        let B = RistrettoPoint::random(&mut rng);
+        let table = RistrettoBasepointTable::create(&B);
+
+        let bytes = table.to_bytes();
+        let decoded = RistrettoBasepointTable::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.basepoint(), B);
+
+        let s = Scalar::random(&mut rng);
+        assert_eq!(&decoded * &s, B * s);
+
+        assert_eq!(
+            RistrettoBasepointTable::from_bytes(&bytes[1..]).err(),
+            Some(TableEncodingError::InvalidMagic)
+        );
+
+        // A table of a point with a torsion component of order 8 is a valid Edwards table, but
+        // its basepoint does not represent any Ristretto point
+        let torsioned = EdwardsBasepointTable::create(&(B.0 + constants::EIGHT_TORSION[1]));
+        assert_eq!(
+            RistrettoBasepointTable::from_bytes(&torsioned.to_bytes()).err(),
+            Some(TableEncodingError::InvalidBasepoint)
+        );
+
+        #[cfg(feature = "serde")]
+        {
+            let encoded = bincode::serialize(&table).unwrap();
+            let decoded: RistrettoBasepointTable = bincode::deserialize(&encoded).unwrap();
+            assert_eq!(decoded.to_bytes(), bytes);
+        }
+    }
+
     #[test]
     fn elligator_vs_ristretto_sage() {
         // Test vectors extracted from ristretto.sage.
@@ -1794,6 +2349,59 @@ mod test {
         }
     }
 
+    // Known answer tests for hash_to_curve, taken from the ristretto255-SHA512 vectors of
+    // RFC 9497, appendix A.1. Each BlindedElement is Blind * HashToGroup(Input), where
+    // HashToGroup is hash_to_ristretto255 with the DST "HashToGroup-" || contextString. The
+    // second input of each mode is 17 bytes of 0x5a, i.e. `Z`.
+    #[test]
+    #[cfg(feature = "digest")]
+    fn hash_to_curve_rfc9497_test_vectors() {
+        let blind = Scalar::from_bytes_mod_order([
+            0x64, 0xd3, 0x7a, 0xed, 0x22, 0xa2, 0x7f, 0x51, 0x91, 0xde, 0x1c, 0x1d, 0x69, 0xfa,
+            0xdb, 0x89, 0x9d, 0x88, 0x62, 0xb5, 0x8e, 0xb4, 0x22, 0x00, 0x29, 0xe0, 0x36, 0xec,
+            0x4c, 0x1f, 0x67, 0x06,
+        ]);
+        let test_vectors: &[(&[u8], &[u8], &str)] = &[
+            (
+                b"HashToGroup-OPRFV1-\x00-ristretto255-SHA512",
+                b"\x00",
+                "609a0ae68c15a3cf6903766461307e5c8bb2f95e7e6550e1ffa2dc99e412803c",
+            ),
+            (
+                b"HashToGroup-OPRFV1-\x00-ristretto255-SHA512",
+                b"ZZZZZZZZZZZZZZZZZ",
+                "da27ef466870f5f15296299850aa088629945a17d1f5b7f5ff043f76b3c06418",
+            ),
+            (
+                b"HashToGroup-OPRFV1-\x01-ristretto255-SHA512",
+                b"\x00",
+                "863f330cc1a1259ed5a5998a23acfd37fb4351a793a5b3c090b642ddc439b945",
+            ),
+            (
+                b"HashToGroup-OPRFV1-\x01-ristretto255-SHA512",
+                b"ZZZZZZZZZZZZZZZZZ",
+                "cc0b2a350101881d8a4cba4c80241d74fb7dcbfde4a61fde2f91443c2bf9ef0c",
+            ),
+            (
+                b"HashToGroup-OPRFV1-\x02-ristretto255-SHA512",
+                b"\x00",
+                "c8713aa89241d6989ac142f22dba30596db635c772cbf25021fdd8f3d461f715",
+            ),
+            (
+                b"HashToGroup-OPRFV1-\x02-ristretto255-SHA512",
+                b"ZZZZZZZZZZZZZZZZZ",
+                "f0f0b209dd4d5f1844dac679acc7761b91a2e704879656cb7c201e82a99ab07d",
+            ),
+        ];
+        for (dst, input, blinded_element) in test_vectors {
+            let P = RistrettoPoint::hash_to_curve::<sha2::Sha512>(input, dst);
+            assert_eq!(
+                hex::encode((blind * P).compress().as_bytes()),
+                *blinded_element
+            );
+        }
+    }
+
     #[test]
     fn random_roundtrip() {
         let mut rng = OsRng;
@@ -1822,6 +2430,72 @@ mod test {
         }
     }
 
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "rand_core"))]
+    fn decompress_with_hint_agrees_with_decompress() {
+        let mut encodings: Vec<CompressedRistretto> = (0..16)
+            .map(|_| RistrettoPoint::random(&mut OsRng).compress())
+            .collect();
+        encodings.push(RistrettoPoint::identity().compress());
+        // Small even values of s, most of which fail the square check in step 2
+        for i in 0..64u8 {
+            let mut bytes = [0u8; 32];
+            bytes[0] = 2 * i;
+            encodings.push(CompressedRistretto(bytes));
+        }
+        // Negative and non-canonical s
+        encodings.push(CompressedRistretto([1u8; 32]));
+        encodings.push(CompressedRistretto([0xff; 32]));
+
+        for encoding in encodings.iter() {
+            let P = encoding.decompress();
+            match encoding.decompress_hint() {
+                Some(hint) => {
+                    assert!(P.is_some());
+                    assert_eq!(encoding.decompress_with_hint(&hint), P);
+
+                    // The negated inverse square root, and any other value, is rejected
+                    let I = FieldElement::from_bytes(&hint);
+                    assert_eq!(encoding.decompress_with_hint(&(-&I).as_bytes()), None);
+                    assert_eq!(
+                        encoding.decompress_with_hint(&(&I + &FieldElement::ONE).as_bytes()),
+                        None
+                    );
+                }
+                None => {
+                    assert!(P.is_none());
+                    assert_eq!(encoding.decompress_with_hint(&[0u8; 32]), None);
+                    assert_eq!(
+                        encoding.decompress_with_hint(&FieldElement::ONE.as_bytes()),
+                        None
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn compress_batch_1024_random_points() {
+        let mut rng = OsRng;
+
+        let mut points: Vec<RistrettoPoint> = (0..1024)
+            .map(|_| RistrettoPoint::random(&mut rng))
+            .collect();
+        points[500] = RistrettoPoint::identity();
+        // A different representative of the same coset must encode identically.
+        points[501] = RistrettoPoint(points[0].0 + constants::EIGHT_TORSION[2]);
+
+        let compressed = RistrettoPoint::compress_batch(&points);
+
+        assert_eq!(compressed.len(), points.len());
+        for (P, P_compressed) in points.iter().zip(compressed.iter()) {
+            assert_eq!(*P_compressed, P.compress());
+        }
+        assert_eq!(compressed[500], CompressedRistretto::identity());
+        assert_eq!(compressed[501], compressed[0]);
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn vartime_precomputed_vs_nonprecomputed_multiscalar() {
@@ -2013,4 +2687,26 @@ mod test {
 
         assert_eq!(result_multiscalar, result_manual);
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn multiscalar_accumulator() {
+        let mut rng = rand::thread_rng();
+
+        let n = 300;
+        let scalars = (0..n).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+        let points = (0..n)
+            .map(|_| RistrettoPoint::random(&mut rng))
+            .collect::<Vec<_>>();
+
+        let mut accumulator = RistrettoMultiscalarAccumulator::with_capacity(64);
+        for (scalar, point) in scalars.iter().zip(points.iter()) {
+            accumulator.push(*scalar, *point);
+        }
+
+        assert_eq!(
+            accumulator.finish(),
+            RistrettoPoint::vartime_multiscalar_mul(&scalars, &points)
+        );
+    }
 }
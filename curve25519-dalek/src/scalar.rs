@@ -138,6 +138,11 @@ use digest::generic_array::typenum::U64;
 #[cfg(feature = "digest")]
 use digest::Digest;
 
+#[cfg(all(feature = "alloc", feature = "digest"))]
+use crate::expand_message::ExpandMessage;
+#[cfg(all(feature = "alloc", feature = "digest"))]
+use alloc::vec::Vec;
+
 use subtle::Choice;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
@@ -677,6 +682,60 @@ impl Scalar {
         Scalar::from_bytes_mod_order_wide(&output)
     }
 
+    #[cfg(all(feature = "alloc", feature = "digest"))]
+    /// Hash a message to `count` scalars with domain separation tag `dst`, as specified by the
+    /// `hash_to_field` function of [RFC 9380][rfc9380].
+    ///
+    /// The message is the concatenation of `msgs`, and is expanded with the
+    /// [`ExpandMessage`] implementation `E`, such as
+    /// [`ExpandMsgXmd<Sha512>`](crate::expand_message::ExpandMsgXmd) or
+    /// [`ExpandMsgXof<Shake256, 256>`](crate::expand_message::ExpandMsgXof). Each scalar is
+    /// derived from \\(L = 48\\) bytes of the expanded message, read as a big-endian integer
+    /// and reduced modulo \\(\ell\\), so that the bias of the result is negligible.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is empty, or if `48 * count` bytes is more than `E` can produce.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// use curve25519_dalek::expand_message::{ExpandMsgXmd, ExpandMsgXof};
+    /// use sha2::Sha512;
+    /// use sha3::Shake256;
+    ///
+    /// # fn main() {
+    /// let dst = b"MyProtocol-V1-CS01-with-ristretto255_XMD:SHA-512_R255MAP_RO_";
+    /// let s = Scalar::hash_to_field::<ExpandMsgXmd<Sha512>>(&[b"Hello ", b"world"], dst, 1);
+    /// assert_eq!(s, Scalar::hash_to_field::<ExpandMsgXmd<Sha512>>(&[b"Hello world"], dst, 1));
+    ///
+    /// let dst = b"MyProtocol-V1-CS01-with-ristretto255_XOF:SHAKE256_R255MAP_RO_";
+    /// let t = Scalar::hash_to_field::<ExpandMsgXof<Shake256, 256>>(&[b"Hello world"], dst, 3);
+    /// assert_eq!(t.len(), 3);
+    /// # }
+    /// ```
+    ///
+    /// [rfc9380]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2
+    pub fn hash_to_field<E>(msgs: &[&[u8]], dst: &[u8], count: usize) -> Vec<Scalar>
+    where
+        E: ExpandMessage,
+    {
+        let mut uniform_bytes = vec![0u8; 48 * count];
+        E::expand_message(msgs, dst, &mut uniform_bytes);
+
+        uniform_bytes
+            .chunks(48)
+            .map(|chunk| {
+                // The chunk is a big-endian integer; reverse it into a little-endian buffer.
+                let mut wide = [0u8; 64];
+                wide[..48].copy_from_slice(chunk);
+                wide[..48].reverse();
+                Scalar::from_bytes_mod_order_wide(&wide)
+            })
+            .collect()
+    }
+
     /// Convert this `Scalar` to its underlying sequence of bytes.
     ///
     /// # Example
@@ -1709,6 +1768,82 @@ pub(crate) mod test {
         }
     }
 
+    /// Known answers for `hash_to_field` with count 2 and msg "abc", computed from the
+    /// `expand_message` outputs with an independent implementation of RFC 9380 in Python.
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "digest"))]
+    fn hash_to_field_known_answers() {
+        use crate::expand_message::{ExpandMsgXmd, ExpandMsgXof};
+
+        let dst = b"QUUX-V01-CS02-with-ristretto255_XMD:SHA-512_R255MAP_RO_";
+        let s = Scalar::hash_to_field::<ExpandMsgXmd<sha2::Sha512>>(&[b"abc"], dst, 2);
+        assert_eq!(
+            hex::encode(s[0].as_bytes()),
+            "3c87b744fe49b5bd48dca8bb99c3959b63df830ee36711edea01019c5898d606"
+        );
+        assert_eq!(
+            hex::encode(s[1].as_bytes()),
+            "586b627d6d4f345b574a7a499cc0daf6a19fbdd6fa593c9d6406ba50c5c9be07"
+        );
+
+        let dst = b"QUUX-V01-CS02-with-ristretto255_XOF:SHAKE256_R255MAP_RO_";
+        let s = Scalar::hash_to_field::<ExpandMsgXof<sha3::Shake256, 256>>(&[b"abc"], dst, 2);
+        assert_eq!(
+            hex::encode(s[0].as_bytes()),
+            "b31efc63715df6b2326fc1755ceaf5eaffa3288ea6555ffcf9a74cca3b8e7108"
+        );
+        assert_eq!(
+            hex::encode(s[1].as_bytes()),
+            "904400d4763d17c318e30268051bc19cd79b0a9c873baf865375d154cbf3b10a"
+        );
+
+        // The output length is an input to the expansion, so the scalars depend on `count`.
+        let t = Scalar::hash_to_field::<ExpandMsgXof<sha3::Shake256, 256>>(&[b"abc"], dst, 1);
+        assert_eq!(t.len(), 1);
+        assert_ne!(t[0], s[0]);
+    }
+
+    /// `hash_to_field` over published uniform bytes: the `expand_message_xmd(SHA-512)` output
+    /// for msg "abc" and `len_in_bytes = 0x80` from RFC 9380, appendix K.3.  The expected
+    /// scalars are the first two 48-byte big-endian chunks of that output reduced modulo
+    /// \\(\ell\\).
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "digest"))]
+    fn hash_to_field_rfc9380_uniform_bytes() {
+        use crate::expand_message::ExpandMessage;
+
+        struct PublishedXmdSha512;
+
+        impl ExpandMessage for PublishedXmdSha512 {
+            fn expand_message(msgs: &[&[u8]], dst: &[u8], out: &mut [u8]) {
+                assert_eq!(msgs, &[b"abc"]);
+                assert_eq!(dst, b"QUUX-V01-CS02-with-expander-SHA512-256");
+                let uniform_bytes = hex::decode(
+                    "7f1dddd13c08b543f2e2037b14cefb255b44c83cc397c1786d975653e36a6b11\
+                     bdd7732d8b38adb4a0edc26a0cef4bb45217135456e58fbca1703cd6032cb134\
+                     7ee720b87972d63fbf232587043ed2901bce7f22610c0419751c065922b48843\
+                     1851041310ad659e4b23520e1772ab29dcdeb2002222a363f0c2b1c972b3efe1",
+                )
+                .expect("invalid hex");
+                out.copy_from_slice(&uniform_bytes[..out.len()]);
+            }
+        }
+
+        let s = Scalar::hash_to_field::<PublishedXmdSha512>(
+            &[b"abc"],
+            b"QUUX-V01-CS02-with-expander-SHA512-256",
+            2,
+        );
+        assert_eq!(
+            hex::encode(s[0].as_bytes()),
+            "456b4cadc52e4d87c01da63faec628c0bb196fb2d92ca205a75740e867f37305"
+        );
+        assert_eq!(
+            hex::encode(s[1].as_bytes()),
+            "1622a0f28deb5a81f2cf380c1dfbf82e337592cd571b3d53426a81796546d203"
+        );
+    }
+
     #[allow(non_snake_case)]
     #[test]
     fn invert() {
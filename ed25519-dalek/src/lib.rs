@@ -176,6 +176,22 @@
 //!     .expect("invalid public key PEM");
 //! ```
 //!
+//! ### Verification-only Builds
+//!
+//! Programs which only ever verify signatures get the smallest build with
+//! `default-features = false`:
+//!
+//! ```toml
+//! ed25519-dalek = { version = "2", default-features = false }
+//! ```
+//!
+//! Verification computes `[s]B - [k]A` with a variable-time double-base
+//! multiplication. With the default `fast` feature, this uses a large
+//! precomputed table of multiples of the basepoint; without it, a small
+//! table is built at runtime instead. Verification results are identical
+//! either way, and the signing code is left out by the linker when it is
+//! not used.
+//!
 //! ### Using Serde
 //!
 //! If you prefer the bytes to be wrapped in another serialisation format, all
@@ -432,6 +432,30 @@ mod integrations {
         assert!(result.is_ok());
     }
 
+    #[cfg(feature = "batch")]
+    #[test]
+    fn verify_batch_many_signatures() {
+        // Enough signatures for the multiscalar multiplication to use Pippenger's algorithm
+        let mut csprng = OsRng;
+        let messages: Vec<Vec<u8>> = (0..128u8).map(|i| vec![i; 32]).collect();
+        let messages: Vec<&[u8]> = messages.iter().map(|msg| &msg[..]).collect();
+        let signing_keys: Vec<SigningKey> = (0..messages.len())
+            .map(|_| SigningKey::generate(&mut csprng))
+            .collect();
+        let mut signatures: Vec<Signature> = signing_keys
+            .iter()
+            .zip(messages.iter())
+            .map(|(key, msg)| key.sign(msg))
+            .collect();
+        let verifying_keys: Vec<VerifyingKey> =
+            signing_keys.iter().map(|key| key.verifying_key()).collect();
+
+        assert!(verify_batch(&messages, &signatures, &verifying_keys).is_ok());
+
+        signatures.swap(0, 1);
+        assert!(verify_batch(&messages, &signatures, &verifying_keys).is_err());
+    }
+
     #[test]
     fn public_key_hash_trait_check() {
         let mut csprng = OsRng {};
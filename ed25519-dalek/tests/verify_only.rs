@@ -0,0 +1,137 @@
+// -*- mode: rust; -*-
+//
+// This file is part of ed25519-dalek.
+// See LICENSE for licensing information.
+
+//! Verification tests which only use the public half of each key pair.
+//!
+//! These run with every feature set, including the small-footprint `default-features = false`
+//! build, and check that it accepts and rejects exactly the same signatures as the default one.
+
+use ed25519_dalek::*;
+
+use hex::FromHex;
+#[cfg(feature = "digest")]
+use hex_literal::hex;
+
+use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
+
+/// Read the (verifying key, message, signature) triples from the TESTVECTORS file.
+fn reference_signatures() -> Vec<(VerifyingKey, Vec<u8>, Signature)> {
+    let f = File::open("TESTVECTORS").expect(
+        "This test is only available when the code has been cloned from the git repository, \
+         since the TESTVECTORS file is large and is therefore not included within the \
+         distributed crate.",
+    );
+    let file = BufReader::new(f);
+
+    file.lines()
+        .enumerate()
+        .map(|(lineno, l)| {
+            let line = l.unwrap();
+
+            let parts: Vec<&str> = line.split(':').collect();
+            assert_eq!(parts.len(), 5, "wrong number of fields in line {}", lineno);
+
+            let pub_bytes: Vec<u8> = FromHex::from_hex(parts[1]).unwrap();
+            let msg_bytes: Vec<u8> = FromHex::from_hex(parts[2]).unwrap();
+            let sig_bytes: Vec<u8> = FromHex::from_hex(parts[3]).unwrap();
+
+            let pub_bytes = &pub_bytes[..PUBLIC_KEY_LENGTH].try_into().unwrap();
+            let verifying_key = VerifyingKey::from_bytes(pub_bytes).unwrap();
+
+            // The signatures in the test vectors also include the message
+            // at the end, but we just want R and S.
+            let signature = Signature::try_from(&sig_bytes[..64]).unwrap();
+
+            (verifying_key, msg_bytes, signature)
+        })
+        .collect()
+}
+
+/// Verify the signatures from the TESTVECTORS file, and check that changing the message makes
+/// each of them fail.
+#[test]
+fn verify_reference_signatures() {
+    for (lineno, (verifying_key, mut msg_bytes, signature)) in
+        reference_signatures().into_iter().enumerate()
+    {
+        assert!(
+            verifying_key.verify(&msg_bytes, &signature).is_ok(),
+            "Signature verification failed on line {}",
+            lineno
+        );
+        assert!(
+            verifying_key.verify_strict(&msg_bytes, &signature).is_ok(),
+            "Signature strict verification failed on line {}",
+            lineno
+        );
+
+        msg_bytes.push(0x00);
+        assert!(
+            verifying_key.verify(&msg_bytes, &signature).is_err(),
+            "Signature verification succeeded on a modified message on line {}",
+            lineno
+        );
+        assert!(
+            verifying_key.verify_strict(&msg_bytes, &signature).is_err(),
+            "Signature strict verification succeeded on a modified message on line {}",
+            lineno
+        );
+    }
+}
+
+/// Batch verify the signatures from the TESTVECTORS file, and check that changing any one
+/// message makes the batch fail.
+#[cfg(feature = "batch")]
+#[test]
+fn verify_batch_reference_signatures() {
+    let vectors = reference_signatures();
+
+    for batch in vectors.chunks(64) {
+        let mut messages: Vec<Vec<u8>> = batch.iter().map(|(_, msg, _)| msg.clone()).collect();
+        let signatures: Vec<Signature> = batch.iter().map(|(_, _, sig)| *sig).collect();
+        let verifying_keys: Vec<VerifyingKey> = batch.iter().map(|(vk, _, _)| *vk).collect();
+
+        let message_refs: Vec<&[u8]> = messages.iter().map(|msg| &msg[..]).collect();
+        assert!(verify_batch(&message_refs, &signatures, &verifying_keys).is_ok());
+
+        let last = messages.len() - 1;
+        messages[last].push(0x00);
+        let message_refs: Vec<&[u8]> = messages.iter().map(|msg| &msg[..]).collect();
+        assert!(verify_batch(&message_refs, &signatures, &verifying_keys).is_err());
+    }
+
+    // Mismatched lengths are rejected before any verification
+    let (verifying_key, msg, signature) = &vectors[0];
+    assert!(verify_batch(&[&msg[..]], &[*signature, *signature], &[*verifying_key]).is_err());
+}
+
+// From https://tools.ietf.org/html/rfc8032#section-7.3
+#[cfg(feature = "digest")]
+#[test]
+fn verify_ed25519ph_rfc8032_test_vector() {
+    let pub_bytes = hex!("ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf");
+    let msg_bytes = hex!("616263");
+    let sig_bytes = hex!("98a70222f0b8121aa9d30f813d683f809e462b469c7ff87639499bb94e6dae4131f85042463c2a355a2003d062adf5aaa10b8c61e636062aaad11c2a26083406");
+
+    let verifying_key = VerifyingKey::from_bytes(&pub_bytes).unwrap();
+    let signature = Signature::from_bytes(&sig_bytes);
+
+    let mut prehashed_message = Sha512::default();
+    prehashed_message.update(&msg_bytes[..]);
+
+    assert!(verifying_key
+        .verify_prehashed(prehashed_message.clone(), None, &signature)
+        .is_ok());
+    assert!(verifying_key
+        .verify_prehashed_strict(prehashed_message.clone(), None, &signature)
+        .is_ok());
+
+    // The signature was made without a context
+    assert!(verifying_key
+        .verify_prehashed(prehashed_message, Some(b"context"), &signature)
+        .is_err());
+}